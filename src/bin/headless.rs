@@ -0,0 +1,62 @@
+extern crate ghgj18;
+
+use std::{
+  env,
+  process,
+};
+
+//Usage: headless [--seed <seed>] [level] [frames]
+//       headless --replay <file> [frames]
+//Exits with 1 if the level wasn't won by the end of the run
+fn main() {
+  let mut args = env::args().skip(1).peekable();
+
+  let mut replay = None;
+  let mut seed = None;
+  loop {
+    match args.peek().map(|a| a.as_str()) {
+      Some("--replay") => {},
+      Some("--seed") => {},
+      _ => break,
+    }
+    let flag = args.next().unwrap();
+    let value = args.next().expect(&format!("{} needs a value", flag));
+    if flag == "--replay" {
+      replay = Some(value);
+    } else {
+      seed = Some(value.parse::<u64>().expect("seed must be a positive integer"));
+    }
+  }
+
+  let level = if replay.is_none() {
+    args
+      .next()
+      .map(|a| a.parse().expect("level must be a positive integer"))
+      .unwrap_or(0)
+  } else {
+    0
+  };
+  let frames = args
+    .next()
+    .map(|a| a.parse().expect("frames must be a positive integer"))
+    .unwrap_or(60 * 60);
+
+  let stats = match replay {
+    Some(ref replay) => ghgj18::run_headless_replay(replay, frames),
+    None => ghgj18::run_headless(level, frames, seed),
+  };
+
+  println!("{} after {} frames: spawned {}/{}, saved {}, killed {}, saved {:.*}% (needed {:.*}%)",
+    replay.map_or(format!("Level {}", level), |r| format!("Replay {}", r)),
+    frames,
+    stats.spawned,
+    stats.total,
+    stats.saved,
+    stats.killed,
+    0, stats.saved_ratio() * 100.0,
+    0, stats.win_ratio * 100.0);
+
+  if stats.saved_ratio() < stats.win_ratio {
+    process::exit(1);
+  }
+}
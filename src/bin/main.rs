@@ -1,5 +1,30 @@
 extern crate ghgj18;
 extern crate amethyst;
+
+use std::{
+  env,
+  process,
+};
+
+//Usage: main [--seed <seed>] [--replay <file>]
+//       main validate
 fn main() -> Result<(), amethyst::Error> {
-  ghgj18::run()
-}
\ No newline at end of file
+  let args: Vec<String> = env::args().skip(1).collect();
+
+  if args.first().map_or(false, |a| a == "validate") {
+    let valid = ghgj18::validate_levels();
+    process::exit(if valid { 0 } else { 1 });
+  }
+
+  let arg_value = |name: &str| {
+    args
+      .iter()
+      .position(|a| a == name)
+      .map(|i| args.get(i + 1).expect(&format!("{} needs a value", name)).clone())
+  };
+
+  match arg_value("--replay") {
+    Some(replay) => ghgj18::run_replay(&replay),
+    None => ghgj18::run(arg_value("--seed").map(|s| s.parse().expect("seed must be a positive integer"))),
+  }
+}
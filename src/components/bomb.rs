@@ -0,0 +1,9 @@
+///Component for a bomb that explodes once its age passes the fuse (in seconds)
+#[derive(Debug, Clone, Copy)]
+pub struct Bomb {
+  pub fuse: f32,
+}
+
+///Component for blocks that get destroyed when a bomb goes off near them
+#[derive(Debug, Clone, Default)]
+pub struct Destructible;
@@ -0,0 +1,12 @@
+use amethyst::core::cgmath::Vector2;
+
+use ::resources::BodyMaterial;
+
+///Component for walls that the dig ability can cut through. Keeps the wall's geometry so it can be split up.
+#[derive(Debug, Clone)]
+pub struct Diggable {
+  pub position: Vector2<f32>,
+  pub size: Vector2<f32>,
+  pub rotation: f32,
+  pub material: BodyMaterial,
+}
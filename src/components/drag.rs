@@ -0,0 +1,9 @@
+///Component that stops a physics object falling faster than max_fall_velocity (in meters per second)
+#[derive(Debug, Clone, Copy)]
+pub struct Drag {
+  pub max_fall_velocity: f32,
+}
+
+///Component that gives walkers drag when their physics bodies overlap
+#[derive(Debug, Clone, Default)]
+pub struct FloaterArea;
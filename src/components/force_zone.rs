@@ -0,0 +1,22 @@
+use amethyst::core::cgmath::Vector2;
+
+///Which bodies a force zone pushes
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub enum ForceFilter {
+  Walkers,
+  All,
+}
+
+impl Default for ForceFilter {
+  fn default() -> Self {
+    ForceFilter::All
+  }
+}
+
+///Sensor that keeps pushing the bodies inside it, for wind, updrafts and conveyor belts
+#[derive(Debug, Clone)]
+pub struct ForceZone {
+  //Applied the same way as walker_force so it's really an acceleration (meters per second squared)
+  pub force: Vector2<f32>,
+  pub filter: ForceFilter,
+}
\ No newline at end of file
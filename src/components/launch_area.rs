@@ -1,6 +1,9 @@
 use std::collections::HashSet;
 
-use amethyst::ecs::prelude::*;
+use amethyst::{
+  ecs::prelude::*,
+  core::cgmath::Vector2,
+};
 
 use super::Direction;
 
@@ -8,14 +11,17 @@ use super::Direction;
 #[derive(Debug, Clone, Default)]
 pub struct LaunchArea {
   pub direction: Direction,
+  //Launch velocity (meters per second) when going right, mirrored for left. None uses the physics config lift_velocity
+  pub velocity: Option<Vector2<f32>>,
   //This is mainly used to stop the lift sound playing over and over
   pub already_launched: HashSet<Entity>,
 }
 
 impl LaunchArea {
-  pub fn new(direction: Direction) -> Self {
+  pub fn new(direction: Direction, velocity: Option<Vector2<f32>>) -> Self {
     Self {
       direction,
+      velocity,
       already_launched: HashSet::new(),
     }
   }
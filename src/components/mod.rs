@@ -22,6 +22,13 @@ mod deadly_area;
 mod age;
 mod launch_area;
 mod constant_velocity;
+mod diggable;
+mod drag;
+mod bomb;
+mod platform;
+mod switch;
+mod teleporter;
+mod force_zone;
 
 pub use self::basic_velocity::*;
 pub use self::physics::*;
@@ -36,6 +43,13 @@ pub use self::deadly_area::*;
 pub use self::age::*;
 pub use self::launch_area::*;
 pub use self::constant_velocity::*;
+pub use self::diggable::*;
+pub use self::drag::*;
+pub use self::bomb::*;
+pub use self::platform::*;
+pub use self::switch::*;
+pub use self::teleporter::*;
+pub use self::force_zone::*;
 
 //TODO: I've just used BTreeStorage for all of these as the specs book suggests it's ok as a general default.
 //  Think about using more appropriate storages at some point.
@@ -71,6 +85,10 @@ impl Component for ChangeDirection {
   type Storage = BTreeStorage<Self>;
 }
 
+impl Component for Blocker {
+  type Storage = BTreeStorage<Self>;
+}
+
 impl Component for Color {
   type Storage = BTreeStorage<Self>;
 }
@@ -97,4 +115,44 @@ impl Component for LaunchArea {
 
 impl Component for ConstantVelocity {
   type Storage = BTreeStorage<Self>;
+}
+
+impl Component for Diggable {
+  type Storage = BTreeStorage<Self>;
+}
+
+impl Component for Drag {
+  type Storage = BTreeStorage<Self>;
+}
+
+impl Component for FloaterArea {
+  type Storage = NullStorage<Self>;
+}
+
+impl Component for Bomb {
+  type Storage = BTreeStorage<Self>;
+}
+
+impl Component for Destructible {
+  type Storage = NullStorage<Self>;
+}
+
+impl Component for Platform {
+  type Storage = BTreeStorage<Self>;
+}
+
+impl Component for Switch {
+  type Storage = BTreeStorage<Self>;
+}
+
+impl Component for Door {
+  type Storage = BTreeStorage<Self>;
+}
+
+impl Component for Teleporter {
+  type Storage = BTreeStorage<Self>;
+}
+
+impl Component for ForceZone {
+  type Storage = BTreeStorage<Self>;
 }
\ No newline at end of file
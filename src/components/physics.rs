@@ -30,6 +30,8 @@ pub struct Collider {
   //Used for lerping between steps
   pub transform_current: Transform,
   pub transform_next: Transform,
+  //Fastest downward velocity (meters per second) of the body over the physics steps taken this frame
+  pub peak_fall_velocity: f32,
 }
 
 impl Collider {
@@ -39,6 +41,7 @@ impl Collider {
       collider_handle,
       transform_current: Transform::default(),
       transform_next: Transform::default(),
+      peak_fall_velocity: 0.0,
     }
   }
 
@@ -0,0 +1,69 @@
+use amethyst::core::cgmath::{
+  Vector2,
+  InnerSpace,
+};
+
+///Component for kinematic bodies that move between waypoints (in pixels)
+#[derive(Debug, Clone)]
+pub struct Platform {
+  pub waypoints: Vec<Vector2<f32>>,
+  //Pixels per second
+  pub speed: f32,
+  //Goes back to the first waypoint after the last instead of reversing
+  pub looping: bool,
+  //Index of the waypoint it's heading for
+  target: usize,
+  //Which way it's going through the waypoints when ping ponging
+  forward: bool,
+}
+
+impl Platform {
+  pub fn new(waypoints: Vec<Vector2<f32>>, speed: f32, looping: bool) -> Self {
+    Self {
+      waypoints,
+      speed,
+      looping,
+      //Starts on the first waypoint
+      target: 1,
+      forward: true,
+    }
+  }
+
+  ///Velocity (in pixels per second) that gets the platform from position towards the next waypoint over the next step
+  pub fn velocity(&mut self, position: Vector2<f32>, timestep: f32) -> Vector2<f32> {
+    if self.waypoints.len() < 2 || self.speed <= 0.0 {
+      return Vector2::new(0.0, 0.0);
+    }
+
+    let to_target = self.waypoints[self.target] - position;
+    let distance = to_target.magnitude();
+    //Lands exactly on the waypoint instead of overshooting it
+    if distance <= self.speed * timestep {
+      self.next_target();
+      return to_target / timestep;
+    }
+
+    to_target / distance * self.speed
+  }
+
+  fn next_target(&mut self) {
+    let last = self.waypoints.len() - 1;
+    if self.looping {
+      self.target = if self.target == last { 0 } else { self.target + 1 };
+    } else if self.forward {
+      if self.target == last {
+        self.forward = false;
+        self.target -= 1;
+      } else {
+        self.target += 1;
+      }
+    } else {
+      if self.target == 0 {
+        self.forward = true;
+        self.target += 1;
+      } else {
+        self.target -= 1;
+      }
+    }
+  }
+}
@@ -1,8 +1,20 @@
-use amethyst::renderer::Shape as aShape;
+use amethyst::{
+  core::cgmath::Vector2,
+  renderer::Shape as aShape,
+};
+
+///How the mesh for a shape gets made
+#[derive(Debug, Clone)]
+pub enum ShapeMesh {
+  //One of amethyst's shapes, stretched by the scale
+  Generated(aShape),
+  //Flat triangles, every three points (in pixels) make one. The scale isn't used
+  Triangles(Vec<Vector2<f32>>),
+}
 
 ///Component represents the shape of an entity
 #[derive(Debug, Clone)]
 pub struct Shape {
-  pub shape: aShape,
+  pub shape: ShapeMesh,
   pub scale: (f32, f32, f32),
 }
\ No newline at end of file
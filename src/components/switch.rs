@@ -0,0 +1,18 @@
+///Component for sensors that press while a walker is on them
+#[derive(Debug, Clone)]
+pub struct Switch {
+  //Ids of the doors it toggles
+  pub targets: Vec<String>,
+  //Stays pressed once it has been pressed
+  pub latch: bool,
+  pub pressed: bool,
+}
+
+///Component for walls that switches open and close
+#[derive(Debug, Clone)]
+pub struct Door {
+  pub id: String,
+  //How the door is when none of its switches are pressed
+  pub open_by_default: bool,
+  pub open: bool,
+}
@@ -0,0 +1,9 @@
+use amethyst::core::cgmath::Vector2;
+
+///Sensor that moves walkers to the other end of its pair
+#[derive(Debug, Clone)]
+pub struct Teleporter {
+  //Middle of the other end (in pixels)
+  pub destination: Vector2<f32>,
+  pub keep_velocity: bool,
+}
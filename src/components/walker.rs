@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
 pub enum Direction {
   Right,
   Left,
@@ -39,4 +39,11 @@ impl Default for Walker {
 #[derive(Debug, Clone)]
 pub struct ChangeDirection {
   pub direction: Direction,
+}
+
+///Component that sends walkers away from it, the way they go depends on which side of it they're on
+#[derive(Debug, Clone)]
+pub struct Blocker {
+  //Where the middle of the blocker is
+  pub x: f32,
 }
\ No newline at end of file
@@ -0,0 +1,173 @@
+use std::{
+  fs,
+  io,
+};
+
+use amethyst::{
+  config::ConfigError,
+  core::cgmath::Vector3,
+};
+
+use ::components::Color;
+
+use super::{
+  Cuboid,
+  CuboidSet,
+  LevelConfig,
+  level_file_path,
+};
+
+//Same depth as the hand written levels use
+const TILE_DEPTH: f32 = 0.1;
+
+///Level geometry drawn with one character per tile in a text file under resources/levels/.
+///  # wall, D diggable wall, X deadly area, E exit, S spawner, B block, . or space for nothing.
+///Touching tiles of the same type are merged into rectangles. The bottom left of the grid is at 0,0.
+///Lines starting with // are comments.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GridLevel {
+  pub file: String,
+  //Width and height of a tile in world units
+  pub tile_size: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+  Empty,
+  Wall,
+  DiggableWall,
+  DeadlyArea,
+  Exit,
+  Spawner,
+  Block,
+}
+
+impl Tile {
+  fn from_char(c: char) -> Option<Self> {
+    match c {
+      '.' | ' ' => Some(Tile::Empty),
+      '#' => Some(Tile::Wall),
+      'D' => Some(Tile::DiggableWall),
+      'X' => Some(Tile::DeadlyArea),
+      'E' => Some(Tile::Exit),
+      'S' => Some(Tile::Spawner),
+      'B' => Some(Tile::Block),
+      _ => None,
+    }
+  }
+}
+
+///Adds the objects from the level's grid file (if it has one) to its sets
+pub fn load_level_grid(level: &mut LevelConfig) -> Result<(), ConfigError> {
+  if let Some(grid) = level.grid.clone() {
+    let path = level_file_path(&grid.file);
+    let text = fs::read_to_string(&path)?;
+    let objects = parse_grid(&text, grid.tile_size)
+      .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path, e)))?;
+
+    for (tile, mut cuboid) in objects {
+      let (set, default_color) = match tile {
+        Tile::Wall => (&mut level.walls, Some(Color::new(0.5, 0.5, 0.5, 1.0))),
+        Tile::DiggableWall => {
+          //Shares the walls set so it gets its own color
+          cuboid.diggable = Some(true);
+          cuboid.color = Some(Color::new(0.55, 0.35, 0.15, 1.0));
+          (&mut level.walls, Some(Color::new(0.5, 0.5, 0.5, 1.0)))
+        },
+        Tile::DeadlyArea => (&mut level.deadly_areas, Some(Color::new(0.8, 0.1, 0.1, 1.0))),
+        Tile::Exit => (&mut level.exits, Some(Color::new(0.1, 0.8, 0.1, 1.0))),
+        Tile::Spawner => (&mut level.spawners, Some(Color::new(0.1, 0.1, 0.8, 1.0))),
+        Tile::Block => (&mut level.blocks, None),
+        Tile::Empty => continue,
+      };
+      //Anything already in the level config is kept so grid levels can still have hand placed objects
+      set
+        .get_or_insert_with(|| CuboidSet {
+          list: Vec::new(),
+          color: default_color,
+          diggable: None,
+          destructible: None,
+          material: None,
+        })
+        .list
+        .push(cuboid);
+    }
+  }
+  Ok(())
+}
+
+//Converts the grid into rectangles, each one as big as it can be going right then down
+fn parse_grid(text: &str, tile_size: f32) -> Result<Vec<(Tile, Cuboid)>, String> {
+  if tile_size <= 0.0 {
+    return Err(format!("tile_size {} must be greater than 0", tile_size));
+  }
+
+  let mut rows = Vec::new();
+  for (line_number, line) in text.lines().enumerate() {
+    if line.trim_left().starts_with("//") {
+      continue;
+    }
+    let mut row = Vec::new();
+    for (column, c) in line.trim_right().chars().enumerate() {
+      match Tile::from_char(c) {
+        Some(tile) => row.push(tile),
+        None => return Err(format!("Unknown tile '{}' on line {} column {}", c, line_number + 1, column + 1)),
+      }
+    }
+    rows.push(row);
+  }
+
+  let height = rows.len();
+  let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+  //Short rows are padded with empty tiles
+  let tile_at = |x: usize, y: usize| *rows[y].get(x).unwrap_or(&Tile::Empty);
+
+  let mut used = vec![vec![false; width]; height];
+  let mut objects = Vec::new();
+  for top in 0..height {
+    for left in 0..width {
+      let tile = tile_at(left, top);
+      if tile == Tile::Empty || used[top][left] {
+        continue;
+      }
+
+      let mut right = left + 1;
+      while right < width && tile_at(right, top) == tile && !used[top][right] {
+        right += 1;
+      }
+
+      let mut bottom = top + 1;
+      while bottom < height && (left..right).all(|x| tile_at(x, bottom) == tile && !used[bottom][x]) {
+        bottom += 1;
+      }
+
+      for y in top..bottom {
+        for x in left..right {
+          used[y][x] = true;
+        }
+      }
+
+      //Rows go down the file but y goes up the screen
+      let tiles_wide = (right - left) as f32;
+      let tiles_high = (bottom - top) as f32;
+      let tiles_below = (height - bottom) as f32;
+      objects.push((tile, Cuboid {
+        size: Vector3::new(tiles_wide * tile_size, tiles_high * tile_size, TILE_DEPTH),
+        position: Vector3::new(
+          (left as f32 + tiles_wide * 0.5) * tile_size,
+          (tiles_below + tiles_high * 0.5) * tile_size,
+          0.0),
+        color: None,
+        rotation: None,
+        diggable: None,
+        destructible: None,
+        material: None,
+        friction: None,
+        restitution: None,
+        density: None,
+      }));
+    }
+  }
+
+  Ok(objects)
+}
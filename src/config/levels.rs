@@ -1,6 +1,28 @@
-use amethyst::core::cgmath::Vector3;
+use amethyst::{
+  config::ConfigError,
+  core::cgmath::{
+    Vector2,
+    Vector3,
+  },
+  prelude::Config,
+  utils::application_root_dir,
+};
 
-use ::components::Color;
+use ::{
+  components::{
+    Color,
+    Direction,
+    ForceFilter,
+  },
+  resources::ColliderShape,
+};
+
+use super::{
+  GridLevel,
+  load_level_grid,
+};
+
+const LEVELS_PATH: &'static str = "resources/levels";
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Cuboid {
@@ -8,12 +30,206 @@ pub struct Cuboid {
   pub position: Vector3<f32>,
   pub color: Option<Color>,
   pub rotation: Option<f32>,
+  //Walls only, can the dig ability cut through it
+  pub diggable: Option<bool>,
+  //Blocks only, do bombs destroy it
+  pub destructible: Option<bool>,
+  //Walls and blocks only, name of one of the physics config materials
+  pub material: Option<String>,
+  //Override the material
+  pub friction: Option<f32>,
+  pub restitution: Option<f32>,
+  pub density: Option<f32>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct CuboidSet {
   pub list: Vec<Cuboid>,
   pub color: Option<Color>,
+  //Defaults for everything in the list
+  pub diggable: Option<bool>,
+  pub destructible: Option<bool>,
+  pub material: Option<String>,
+}
+
+///Shapes for walls and blocks that aren't boxes. Sizes and points are in pixels, points are relative to the position
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub enum ShapeConfig {
+  //Radius
+  Ball(f32),
+  //Uses the convex hull of the points
+  ConvexPolygon(Vec<Vector2<f32>>),
+  //Line segments joining the points, walls only. Good for slopes as there are no seams for creeps to catch on
+  Polyline(Vec<Vector2<f32>>),
+}
+
+impl ShapeConfig {
+  pub fn collider_shape(&self) -> ColliderShape {
+    match self {
+      ShapeConfig::Ball(radius) => ColliderShape::Ball(*radius),
+      ShapeConfig::ConvexPolygon(points) => ColliderShape::ConvexPolygon(points.clone()),
+      ShapeConfig::Polyline(points) => ColliderShape::Polyline(points.clone()),
+    }
+  }
+}
+
+///A wall or block that isn't a box
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ShapedObject {
+  pub shape: ShapeConfig,
+  pub position: Vector3<f32>,
+  pub rotation: Option<f32>,
+  pub color: Option<Color>,
+  //Blocks only
+  pub destructible: Option<bool>,
+  pub material: Option<String>,
+  pub friction: Option<f32>,
+  pub restitution: Option<f32>,
+  pub density: Option<f32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ShapedSet {
+  pub list: Vec<ShapedObject>,
+  pub color: Option<Color>,
+  pub destructible: Option<bool>,
+  pub material: Option<String>,
+}
+
+///What a platform does once it reaches its last waypoint
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum PlatformMode {
+  //Goes back through the waypoints the way it came
+  PingPong,
+  //Carries on to the first waypoint
+  Loop,
+}
+
+///A wall that moves between waypoints, starting at the first one
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MovingPlatform {
+  pub size: Vector3<f32>,
+  pub waypoints: Vec<Vector2<f32>>,
+  //Pixels per second
+  pub speed: f32,
+  //Defaults to PingPong
+  pub mode: Option<PlatformMode>,
+  pub color: Option<Color>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PlatformSet {
+  pub list: Vec<MovingPlatform>,
+  pub color: Option<Color>,
+}
+
+///Sensor that opens or closes the doors it targets while a walker is on it
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SwitchConfig {
+  pub size: Vector3<f32>,
+  pub position: Vector3<f32>,
+  //Ids of the doors it toggles
+  pub targets: Vec<String>,
+  //Stays pressed once a walker has been on it
+  pub latch: Option<bool>,
+  pub color: Option<Color>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SwitchSet {
+  pub list: Vec<SwitchConfig>,
+  pub color: Option<Color>,
+}
+
+///Wall that switches can open and close. Any number of doors can share an id.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DoorConfig {
+  pub id: String,
+  pub size: Vector3<f32>,
+  pub position: Vector3<f32>,
+  pub rotation: Option<f32>,
+  //Starts open and is closed by its switches
+  pub open: Option<bool>,
+  pub color: Option<Color>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DoorSet {
+  pub list: Vec<DoorConfig>,
+  pub color: Option<Color>,
+}
+
+///Two sensors that send walkers that go into one out of the other
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TeleporterPair {
+  pub size: Vector3<f32>,
+  pub a: Vector3<f32>,
+  pub b: Vector3<f32>,
+  //Walkers come out moving the way they went in, otherwise they come out stopped. Defaults to true
+  pub keep_velocity: Option<bool>,
+  pub color: Option<Color>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TeleporterSet {
+  pub list: Vec<TeleporterPair>,
+  pub color: Option<Color>,
+}
+
+///A lift placed by the level rather than dropped by a matriarch. Give it a velocity to make a launch pad
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LiftConfig {
+  pub position: Vector3<f32>,
+  //Defaults to the same size as a dropped lift
+  pub size: Option<Vector3<f32>>,
+  pub direction: Direction,
+  //Meters per second when going right, defaults to the physics config lift_velocity
+  pub velocity: Option<Vector2<f32>>,
+  //Drawn with the lift sprite if there's no color
+  pub color: Option<Color>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LiftSet {
+  pub list: Vec<LiftConfig>,
+  pub color: Option<Color>,
+}
+
+///A direction changer placed by the level rather than dropped by a matriarch
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DirectionChangerConfig {
+  pub position: Vector3<f32>,
+  //Defaults to the same size as a dropped direction changer
+  pub size: Option<Vector3<f32>>,
+  //Which way walkers go after touching it
+  pub direction: Direction,
+  //Drawn with the direction changer sprite if there's no color
+  pub color: Option<Color>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DirectionChangerSet {
+  pub list: Vec<DirectionChangerConfig>,
+  pub color: Option<Color>,
+}
+
+///Area that keeps pushing the bodies inside it
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ForceZoneConfig {
+  pub size: Vector3<f32>,
+  pub position: Vector3<f32>,
+  pub rotation: Option<f32>,
+  //Meters per second squared, the same as the physics config walker_force
+  pub force: Vector2<f32>,
+  //Defaults to All
+  pub filter: Option<ForceFilter>,
+  pub color: Option<Color>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ForceZoneSet {
+  pub list: Vec<ForceZoneConfig>,
+  pub color: Option<Color>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -32,7 +248,21 @@ pub struct SpawnOverides {
   pub win_ratio: f32,
 }
 
+///How many times each ability can be used in a level. Anything left out is unlimited
 #[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AbilityLimits {
+  pub lifts: Option<u32>,
+  pub direction_changers: Option<u32>,
+  pub rams: Option<u32>,
+  pub blockers: Option<u32>,
+  pub bridges: Option<u32>,
+  pub digs: Option<u32>,
+  pub floaters: Option<u32>,
+  pub bombs: Option<u32>,
+  pub cubes: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct LevelConfig {
   pub name: Option<String>,
   pub description: Option<String>,
@@ -41,13 +271,34 @@ pub struct LevelConfig {
   pub exits: Option<CuboidSet>,
   pub spawners: Option<CuboidSet>,
   pub blocks: Option<CuboidSet>,
+  pub shaped_walls: Option<ShapedSet>,
+  pub shaped_blocks: Option<ShapedSet>,
+  pub platforms: Option<PlatformSet>,
+  pub switches: Option<SwitchSet>,
+  pub doors: Option<DoorSet>,
+  pub teleporters: Option<TeleporterSet>,
+  pub lifts: Option<LiftSet>,
+  pub direction_changers: Option<DirectionChangerSet>,
+  pub force_zones: Option<ForceZoneSet>,
+  //Objects from the grid file get added to the sets above when the config is loaded
+  pub grid: Option<GridLevel>,
   pub spawn_overrides: Option<SpawnOverides>,
+  pub abilities: Option<AbilityLimits>,
+  //Overrides physics.fall_damage_velocity
+  pub fall_damage_velocity: Option<f32>,
   pub camera_overrides: Option<CameraOverrides>,
+  //Set instead of the fields above when the file couldn't be loaded so the other levels keep their indexes
+  #[serde(skip)]
+  pub load_error: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct LevelsConfig {
   pub start_level: Option<usize>,
+  //One RON file per level under resources/levels/
+  pub level_files: Vec<String>,
+  //Loaded from level_files by load_levels
+  #[serde(skip)]
   pub levels: Vec<LevelConfig>,
 }
 
@@ -55,7 +306,55 @@ impl Default for LevelsConfig {
   fn default() -> Self {
     Self {
       start_level: None,
+      level_files: Vec::new(),
       levels: Vec::new(),
     }
   }
+}
+
+impl LevelsConfig {
+  ///The files the level at index is built from (its RON file and grid file if it has one). Used to watch for changes
+  pub fn level_paths(&self, index: usize) -> Vec<String> {
+    let mut paths = Vec::new();
+    if let Some(file) = self.level_files.get(index) {
+      paths.push(level_file_path(file));
+    }
+    if let Some(grid) = self.levels.get(index).and_then(|l| l.grid.as_ref()) {
+      paths.push(level_file_path(&grid.file));
+    }
+    paths
+  }
+
+  ///Whether the level at index couldn't be loaded from its file
+  pub fn failed_to_load(&self, index: usize) -> bool {
+    self.levels.get(index).map_or(false, |l| l.load_error.is_some())
+  }
+}
+
+///Full path of a file in resources/levels/
+pub fn level_file_path(file: &str) -> String {
+  format!("{}/{}/{}", application_root_dir(), LEVELS_PATH, file)
+}
+
+///Loads a single level file, including its grid
+pub fn load_level_config(file: &str) -> Result<LevelConfig, ConfigError> {
+  let mut level = LevelConfig::load_no_fallback(&level_file_path(file))?;
+  load_level_grid(&mut level)?;
+  Ok(level)
+}
+
+///Loads every file in level_files into levels. A file that fails to load becomes an empty level with load_error set
+///rather than stopping the rest from loading
+pub fn load_levels(config: &mut LevelsConfig) {
+  config.levels = config.level_files
+    .iter()
+    .map(|file| match load_level_config(file) {
+      Ok(level) => level,
+      //ConfigError doesn't say which file it came from
+      Err(e) => LevelConfig {
+        load_error: Some(format!("{} failed to load: {}", file, e)),
+        ..Default::default()
+      },
+    })
+    .collect();
 }
\ No newline at end of file
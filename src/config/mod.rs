@@ -14,18 +14,27 @@ mod spawner;
 mod levels;
 mod sound;
 mod sprite;
+mod validate;
+mod grid;
 
 pub use self::pawn::PawnConfig;
-pub use self::physics::PhysicsConfig;
+pub use self::physics::{
+  PhysicsConfig,
+  MaterialConfig,
+};
 pub use self::camera::CameraConfig;
 pub use self::spawner::SpawnerConfig;
 pub use self::levels::*;
 pub use self::sound::SoundConfig;
 pub use self::sprite::SpritesConfig;
+pub use self::validate::*;
+pub use self::grid::*;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GameConfig {
   pub log_level: LevelFilter,
+  //Seed for the GameRng, picked at random if None
+  pub seed: Option<u64>,
   pub pawn: PawnConfig,
   pub physics: PhysicsConfig,
   pub camera: CameraConfig,
@@ -39,6 +48,7 @@ impl Default for GameConfig {
   fn default() -> Self {
     Self {
       log_level: LevelFilter::Debug,
+      seed: None,
       pawn: Default::default(),
       physics: Default::default(),
       camera: Default::default(),
@@ -51,5 +61,7 @@ impl Default for GameConfig {
 }
 
 pub fn load_game_config() -> Result<GameConfig, ConfigError> {
-  GameConfig::load_no_fallback(&format!("{}/resources/config.ron", application_root_dir()))
+  let mut config = GameConfig::load_no_fallback(&format!("{}/resources/config.ron", application_root_dir()))?;
+  load_levels(&mut config.levels);
+  Ok(config)
 }
\ No newline at end of file
@@ -1,5 +1,30 @@
+use std::collections::HashMap;
+
 use amethyst::core::cgmath::Vector2;
 
+use ::{
+  components::Color,
+  resources::BodyMaterial,
+};
+
+///Changes to the default material, anything left out is kept
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct MaterialConfig {
+  pub friction: Option<f32>,
+  pub restitution: Option<f32>,
+  pub density: Option<f32>,
+}
+
+impl MaterialConfig {
+  pub fn apply(&self, material: BodyMaterial) -> BodyMaterial {
+    BodyMaterial {
+      friction: self.friction.unwrap_or(material.friction),
+      restitution: self.restitution.unwrap_or(material.restitution),
+      density: self.density.unwrap_or(material.density),
+    }
+  }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct PhysicsConfig {
   pub gravity: f32,
@@ -17,6 +42,46 @@ pub struct PhysicsConfig {
   pub ram_velocity: Vector2<f32>,
   pub ram_density: f32,
   pub ram_life: f32,
+  pub blocker_width: f32,
+  pub blocker_height: f32,
+  //Width of the area either side of the blocker that turns walkers around
+  pub blocker_sensor_width: f32,
+  //How many planks a bridge has and the size of each one
+  pub bridge_planks: u32,
+  pub bridge_plank_width: f32,
+  pub bridge_plank_height: f32,
+  //Radians above horizontal
+  pub bridge_angle: f32,
+  //Where the bridge starts relative to the middle of the matriarch
+  pub bridge_y_offset: f32,
+  //Width (or height when digging sideways) of the hole dug through a wall
+  pub dig_size: f32,
+  //Walkers that land after falling faster than this (meters per second) die. None turns fall damage off
+  //unless the level sets its own
+  pub fall_damage_velocity: Option<f32>,
+  pub floater_width: f32,
+  pub floater_height: f32,
+  //How fast walkers that went through a floater can fall (meters per second)
+  pub floater_max_fall_velocity: f32,
+  //Seconds from dropping a bomb to it going off
+  pub bomb_fuse: f32,
+  pub bomb_size: f32,
+  //Anything within bomb_radius gets pushed away, creeps within bomb_core_radius die
+  pub bomb_radius: f32,
+  pub bomb_core_radius: f32,
+  //Velocity change (meters per second) right next to the bomb, falls off to nothing at bomb_radius
+  pub bomb_velocity: f32,
+  //Width and height of a dropped cube
+  pub cube_size: f32,
+  pub cube_density: f32,
+  //Seconds before a cube disappears, None keeps it for the rest of the level
+  pub cube_life: Option<f32>,
+  //Cubes are drawn in this color instead of with the sprite if it's set
+  pub cube_color: Option<Color>,
+  //Named materials walls and blocks can use
+  pub materials: HashMap<String, MaterialConfig>,
+  //Seconds after being teleported before a walker can be teleported again
+  pub teleporter_cooldown: f32,
   //How old does a matriarch have to be before it can accept commands
   pub matriarch_grace_period: f32,
 }
@@ -38,6 +103,30 @@ impl Default for PhysicsConfig {
       ram_velocity: Vector2::new(10.0, 0.0),
       ram_density: 100.0,
       ram_life: 0.5,
+      blocker_width: 10.0,
+      blocker_height: 10.0,
+      blocker_sensor_width: 20.0,
+      bridge_planks: 6,
+      bridge_plank_width: 10.0,
+      bridge_plank_height: 2.0,
+      bridge_angle: 0.3,
+      bridge_y_offset: -6.0,
+      dig_size: 14.0,
+      fall_damage_velocity: None,
+      floater_width: 10.0,
+      floater_height: 30.0,
+      floater_max_fall_velocity: 1.0,
+      bomb_fuse: 1.5,
+      bomb_size: 8.0,
+      bomb_radius: 60.0,
+      bomb_core_radius: 20.0,
+      bomb_velocity: 6.0,
+      cube_size: 40.0,
+      cube_density: 1.0,
+      cube_life: None,
+      cube_color: None,
+      materials: HashMap::new(),
+      teleporter_cooldown: 1.0,
       matriarch_grace_period: 0.15,
     }
   }
@@ -0,0 +1,351 @@
+use std::fmt;
+
+use amethyst::core::cgmath::Vector3;
+
+use nalgebra::{
+  Isometry2,
+  Point2,
+  Vector2,
+};
+
+use ncollide2d::{
+  query::{
+    self,
+    Proximity,
+  },
+  shape::{
+    Cuboid as CuboidShape,
+    ConvexPolygon,
+  },
+};
+
+use super::{
+  Cuboid,
+  CuboidSet,
+  ShapeConfig,
+  LevelConfig,
+  LevelsConfig,
+  PhysicsConfig,
+  SpawnerConfig,
+};
+
+//How far two objects have to overlap by (in pixels) before it counts. Stops objects that are just touching being reported
+const OVERLAP_TOLERANCE: f32 = 0.5;
+
+///Something wrong with a level. level and object are None if the problem isn't specific to one.
+#[derive(Debug, Clone)]
+pub struct LevelProblem {
+  pub level: Option<usize>,
+  //Name of the set and the index in it
+  pub object: Option<(&'static str, usize)>,
+  pub message: String,
+}
+
+impl fmt::Display for LevelProblem {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match (self.level, self.object) {
+      (Some(level), Some((set, index))) => write!(f, "Level {}, {}[{}]: {}", level, set, index, self.message),
+      (Some(level), None) => write!(f, "Level {}: {}", level, self.message),
+      _ => write!(f, "{}", self.message),
+    }
+  }
+}
+
+///Checks every level for problems that would stop it loading or make it unplayable. The physics config is needed for
+///the named materials and the spawner config for the defaults levels fall back to
+pub fn find_level_problems(config: &LevelsConfig, physics_config: &PhysicsConfig, spawner_config: &SpawnerConfig) -> Vec<LevelProblem> {
+  let mut problems = Vec::new();
+
+  if spawner_config.win_ratio_default < 0.0 || spawner_config.win_ratio_default > 1.0 {
+    problems.push(LevelProblem {
+      level: None,
+      object: None,
+      message: format!("spawner.win_ratio_default {} is outside 0-1", spawner_config.win_ratio_default),
+    });
+  }
+
+  if config.levels.len() == 0 {
+    problems.push(LevelProblem {
+      level: None,
+      object: None,
+      message: "No levels defined".to_string(),
+    });
+  }
+
+  if let Some(start_level) = config.start_level {
+    if start_level >= config.levels.len() {
+      problems.push(LevelProblem {
+        level: None,
+        object: None,
+        message: format!("start_level {} is past the last level ({})", start_level, config.levels.len() as isize - 1),
+      });
+    }
+  }
+
+  for (i, level) in config.levels.iter().enumerate() {
+    check_level(i, level, physics_config, &mut problems);
+  }
+
+  problems
+}
+
+///Same checks as find_level_problems but for a single level
+pub fn find_problems_in_level(index: usize, level: &LevelConfig, physics_config: &PhysicsConfig) -> Vec<LevelProblem> {
+  let mut problems = Vec::new();
+  check_level(index, level, physics_config, &mut problems);
+  problems
+}
+
+fn check_level(index: usize, level: &LevelConfig, physics_config: &PhysicsConfig, problems: &mut Vec<LevelProblem>) {
+  let mut problem = |object, message: String| problems.push(LevelProblem {
+    level: Some(index),
+    object,
+    message,
+  });
+
+  //There's nothing else to check and it'd only be reported as missing spawners and exits
+  if let Some(ref error) = level.load_error {
+    problem(None, error.clone());
+    return;
+  }
+
+  if set_is_empty(&level.spawners) {
+    problem(None, "No spawners".to_string());
+  }
+
+  if set_is_empty(&level.exits) {
+    problem(None, "No exits".to_string());
+  }
+
+  if let Some(ref overrides) = level.spawn_overrides {
+    if overrides.win_ratio < 0.0 || overrides.win_ratio > 1.0 {
+      problem(None, format!("spawn_overrides.win_ratio {} is outside 0-1", overrides.win_ratio));
+    }
+  }
+
+  let sets = [
+    ("walls", &level.walls),
+    ("deadly_areas", &level.deadly_areas),
+    ("exits", &level.exits),
+    ("spawners", &level.spawners),
+    ("blocks", &level.blocks),
+  ];
+  for &(name, set) in sets.iter() {
+    if let Some(message) = set.as_ref().and_then(|s| unknown_material(&s.material, physics_config)) {
+      problem(None, format!("{}: {}", name, message));
+    }
+    for (i, o) in list(set).iter().enumerate() {
+      if let Some(message) = size_problem(&o.size) {
+        problem(Some((name, i)), message);
+      }
+      if let Some(message) = unknown_material(&o.material, physics_config) {
+        problem(Some((name, i)), message);
+      }
+      for message in material_problems(o.friction, o.restitution, o.density) {
+        problem(Some((name, i)), message);
+      }
+    }
+  }
+
+  let shaped_sets = [
+    ("shaped_walls", &level.shaped_walls),
+    ("shaped_blocks", &level.shaped_blocks),
+  ];
+  for &(name, set) in shaped_sets.iter() {
+    if let Some(ref set) = set {
+      if let Some(message) = unknown_material(&set.material, physics_config) {
+        problem(None, format!("{}: {}", name, message));
+      }
+      for (i, o) in set.list.iter().enumerate() {
+        if let Some(message) = shape_problem(&o.shape) {
+          problem(Some((name, i)), message);
+        }
+        if let ShapeConfig::Polyline(_) = o.shape {
+          if name == "shaped_blocks" {
+            problem(Some((name, i)), "Polylines have no mass so can only be walls".to_string());
+          }
+        }
+        if let Some(message) = unknown_material(&o.material, physics_config) {
+          problem(Some((name, i)), message);
+        }
+        for message in material_problems(o.friction, o.restitution, o.density) {
+          problem(Some((name, i)), message);
+        }
+      }
+    }
+  }
+
+  if let Some(ref set) = level.platforms {
+    for (i, o) in set.list.iter().enumerate() {
+      if let Some(message) = size_problem(&o.size) {
+        problem(Some(("platforms", i)), message);
+      }
+      if o.waypoints.len() == 0 {
+        problem(Some(("platforms", i)), "No waypoints".to_string());
+      }
+      if o.speed < 0.0 {
+        problem(Some(("platforms", i)), format!("Speed {} can't be negative", o.speed));
+      }
+    }
+  }
+
+  //Switches are wired to doors by id so a typo means a switch that does nothing
+  let door_ids: Vec<&str> = match level.doors {
+    Some(ref set) => set.list.iter().map(|d| d.id.as_str()).collect(),
+    None => Vec::new(),
+  };
+  if let Some(ref set) = level.switches {
+    for (i, o) in set.list.iter().enumerate() {
+      if let Some(message) = size_problem(&o.size) {
+        problem(Some(("switches", i)), message);
+      }
+      for target in &o.targets {
+        if !door_ids.contains(&target.as_str()) {
+          problem(Some(("switches", i)), format!("No door with the id \"{}\"", target));
+        }
+      }
+    }
+  }
+  if let Some(ref set) = level.doors {
+    for (i, o) in set.list.iter().enumerate() {
+      if let Some(message) = size_problem(&o.size) {
+        problem(Some(("doors", i)), message);
+      }
+    }
+  }
+
+  if let Some(ref set) = level.teleporters {
+    for (i, o) in set.list.iter().enumerate() {
+      if let Some(message) = size_problem(&o.size) {
+        problem(Some(("teleporters", i)), message);
+      }
+    }
+  }
+
+  if let Some(ref set) = level.lifts {
+    for (i, o) in set.list.iter().enumerate() {
+      if let Some(message) = o.size.as_ref().and_then(size_problem) {
+        problem(Some(("lifts", i)), message);
+      }
+    }
+  }
+  if let Some(ref set) = level.direction_changers {
+    for (i, o) in set.list.iter().enumerate() {
+      if let Some(message) = o.size.as_ref().and_then(size_problem) {
+        problem(Some(("direction_changers", i)), message);
+      }
+    }
+  }
+
+  if let Some(ref set) = level.force_zones {
+    for (i, o) in set.list.iter().enumerate() {
+      if let Some(message) = size_problem(&o.size) {
+        problem(Some(("force_zones", i)), message);
+      }
+    }
+  }
+
+  //Creeps spawned inside a wall get pushed out at high speed or get stuck
+  for (i, spawner) in list(&level.spawners).iter().enumerate() {
+    for (j, wall) in list(&level.walls).iter().enumerate() {
+      if overlaps(spawner, SENSOR_ROTATION, wall, WALL_ROTATION) {
+        problem(Some(("spawners", i)), format!("Overlaps walls[{}]", j));
+      }
+    }
+  }
+
+  //Whichever system runs first wins so the creep may or may not be saved
+  for (i, exit) in list(&level.exits).iter().enumerate() {
+    for (j, deadly_area) in list(&level.deadly_areas).iter().enumerate() {
+      if overlaps(exit, SENSOR_ROTATION, deadly_area, SENSOR_ROTATION) {
+        problem(Some(("exits", i)), format!("Overlaps deadly_areas[{}]", j));
+      }
+    }
+  }
+}
+
+//Ground colliders use the rotation as is but sensors and bodies are rotated the other way (see PhysicsWorld)
+const WALL_ROTATION: f32 = 1.0;
+const SENSOR_ROTATION: f32 = -1.0;
+
+fn material_problems(friction: Option<f32>, restitution: Option<f32>, density: Option<f32>) -> Vec<String> {
+  let mut messages = Vec::new();
+  if let Some(friction) = friction.filter(|f| *f < 0.0) {
+    messages.push(format!("Friction {} can't be negative", friction));
+  }
+  if let Some(restitution) = restitution.filter(|r| *r < 0.0) {
+    messages.push(format!("Restitution {} can't be negative", restitution));
+  }
+  if let Some(density) = density.filter(|d| *d <= 0.0) {
+    messages.push(format!("Density {} must be greater than 0", density));
+  }
+  messages
+}
+
+//Only x and y are used, z is ignored
+fn size_problem(size: &Vector3<f32>) -> Option<String> {
+  if size.x <= 0.0 || size.y <= 0.0 {
+    Some(format!("Size ({}, {}) must be greater than 0", size.x, size.y))
+  } else {
+    None
+  }
+}
+
+//Named materials have to be in the physics config, otherwise the level quietly falls back to the default one
+fn unknown_material(material: &Option<String>, physics_config: &PhysicsConfig) -> Option<String> {
+  match material {
+    Some(name) if !physics_config.materials.contains_key(name) => Some(format!("Material \"{}\" isn't in the physics config's materials", name)),
+    _ => None,
+  }
+}
+
+//Anything the physics world would fail to make a collider from
+fn shape_problem(shape: &ShapeConfig) -> Option<String> {
+  match shape {
+    ShapeConfig::Ball(radius) if *radius <= 0.0 => Some(format!("Radius {} must be greater than 0", radius)),
+    ShapeConfig::ConvexPolygon(points) => {
+      let points: Vec<_> = points.iter().map(|p| Point2::new(p.x, p.y)).collect();
+      if ConvexPolygon::try_from_points(&points).is_none() {
+        Some("Convex polygon points don't make a shape, it needs at least 3 that aren't in a line".to_string())
+      } else {
+        None
+      }
+    },
+    ShapeConfig::Polyline(points) if points.len() < 2 => Some("Polylines need at least 2 points".to_string()),
+    _ => None,
+  }
+}
+
+fn list(set: &Option<CuboidSet>) -> &[Cuboid] {
+  match set {
+    Some(set) => set.list.as_slice(),
+    None => &[],
+  }
+}
+
+fn set_is_empty(set: &Option<CuboidSet>) -> bool {
+  list(set).len() == 0
+}
+
+fn overlaps(a: &Cuboid, a_rotation_sign: f32, b: &Cuboid, b_rotation_sign: f32) -> bool {
+  //Anything this small has already been reported as a size problem
+  let too_small = |o: &Cuboid| o.size.x <= OVERLAP_TOLERANCE * 2.0 || o.size.y <= OVERLAP_TOLERANCE * 2.0;
+  if too_small(a) || too_small(b) {
+    return false;
+  }
+
+  let shape = |o: &Cuboid| CuboidShape::new(Vector2::new(
+    //These are half extents
+    o.size.x * 0.5 - OVERLAP_TOLERANCE,
+    o.size.y * 0.5 - OVERLAP_TOLERANCE,
+  ));
+  let isometry = |o: &Cuboid, sign: f32| Isometry2::new(
+    Vector2::new(o.position.x, o.position.y),
+    o.rotation.unwrap_or(0.0) * sign,
+  );
+
+  match query::proximity(&isometry(a, a_rotation_sign), &shape(a), &isometry(b, b_rotation_sign), &shape(b), 0.0) {
+    Proximity::Intersecting => true,
+    _ => false,
+  }
+}
@@ -0,0 +1,103 @@
+use amethyst::{
+  prelude::Config,
+  core::{
+    bundle::SystemBundle,
+    Time,
+  },
+  ecs::{
+    World,
+    DispatcherBuilder,
+  },
+};
+
+use ::{
+  config::{
+    load_game_config,
+    GameConfig,
+    PhysicsConfig,
+  },
+  resources::{
+    PhysicsWorld,
+    SpawnStats,
+    Replay,
+    GameRng,
+    Level,
+  },
+  systems::GameBundle,
+  create_logger,
+};
+
+//Same as the physics timestep so every frame is exactly one step
+const FRAME_DELTA: f32 = 1.0 / 60.0;
+
+///Plays the level at index `level` for `frames` fixed length frames without a window or audio device
+///and returns the spawn stats at the end. Nothing sends commands so this is mostly useful for checking
+///levels that can be won (or lost) without player input.
+///The seed overrides the one in the config if it's set.
+pub fn run_headless(level: usize, frames: u64, seed: Option<u64>) -> SpawnStats {
+  let game_config = load_game_config().expect("GameConfig failed to load");
+  let seed = seed.or(game_config.seed);
+  simulate(game_config, level, seed, GameBundle::headless(), frames)
+}
+
+///Plays back a replay file for `frames` fixed length frames and returns the spawn stats at the end.
+pub fn run_headless_replay(replay_path: &str, frames: u64) -> SpawnStats {
+  let game_config = load_game_config().expect("GameConfig failed to load");
+  let replay = Replay::load_no_fallback(replay_path).expect("Replay failed to load");
+
+  let level = replay.start_level;
+  let seed = Some(replay.seed);
+  simulate(game_config, level, seed, GameBundle::headless().with_replay(replay), frames)
+}
+
+fn simulate(game_config: GameConfig, level: usize, seed: Option<u64>, game_bundle: GameBundle, frames: u64) -> SpawnStats {
+  create_logger(game_config.log_level);
+
+  let mut world = World::new();
+  world.add_resource(Time::default());
+  world.add_resource(game_config.pawn);
+  world.add_resource(game_config.physics);
+  world.add_resource(game_config.camera);
+  world.add_resource(game_config.spawner);
+  world.add_resource(game_config.sound);
+  world.add_resource(game_config.sprites);
+  world.add_resource(game_config.levels);
+  world.add_resource(GameRng::new(seed));
+  //There's no menu so the level system loads this straight away
+  world.add_resource(Level {
+    current_level: level,
+    ..Level::default()
+  });
+
+  let mut dispatcher = {
+    let mut builder = DispatcherBuilder::new();
+    game_bundle
+      .build(&mut builder)
+      .expect("Failed to build GameBundle");
+    builder.build()
+  };
+  dispatcher.setup(&mut world.res);
+
+  configure_physics(&mut world);
+
+  for _ in 0..frames {
+    {
+      let mut time = world.write_resource::<Time>();
+      time.set_delta_seconds(FRAME_DELTA);
+      time.increment_frame_number();
+    }
+    dispatcher.dispatch(&world.res);
+    //Also runs the LazyUpdate queue which is how the level system loads levels
+    world.maintain();
+  }
+
+  let spawn_stats = world.read_resource::<SpawnStats>();
+  spawn_stats.clone()
+}
+
+//LoadingState does this when running with a window
+fn configure_physics(world: &mut World) {
+  let physics_config = world.read_resource::<PhysicsConfig>();
+  let mut physics_world = world.write_resource::<PhysicsWorld>();
+  physics_world.set_gravity(physics_config.gravity);
+}
@@ -30,7 +30,10 @@ use amethyst::{
 };
 
 mod config;
-use config::load_game_config;
+use config::{
+  load_game_config,
+  find_level_problems,
+};
 
 mod systems;
 use systems::register_systems;
@@ -46,6 +49,19 @@ use states::{
 
 mod components;
 mod resources;
+use resources::{
+  Replay,
+  GameRng,
+  Progress,
+  Level,
+  LoadState,
+};
+
+mod headless;
+pub use headless::{
+  run_headless,
+  run_headless_replay,
+};
 
 fn create_logger(level: LevelFilter) {
   use std::io;
@@ -77,7 +93,29 @@ fn create_logger(level: LevelFilter) {
     .expect("Failed to create fern logger");
 }
 
-pub fn run() -> Result<(), amethyst::Error> {
+///Runs the game. The seed overrides the one in the config if it's set
+pub fn run(seed: Option<u64>) -> Result<(), amethyst::Error> {
+  run_game(seed, None)
+}
+
+///Checks every level in the config and prints any problems. Returns true if there weren't any
+pub fn validate_levels() -> bool {
+  let game_config = load_game_config().expect("GameConfig failed to load");
+  let problems = find_level_problems(&game_config.levels, &game_config.physics, &game_config.spawner);
+  for problem in &problems {
+    println!("{}", problem);
+  }
+  println!("{} problem(s) found in {} level(s)", problems.len(), game_config.levels.levels.len());
+  problems.len() == 0
+}
+
+///Runs the game with commands coming from the replay file instead of the player
+pub fn run_replay(replay_path: &str) -> Result<(), amethyst::Error> {
+  let replay = Replay::load_no_fallback(replay_path).expect("Replay failed to load");
+  run_game(Some(replay.seed), Some(replay))
+}
+
+fn run_game(seed: Option<u64>, replay: Option<Replay>) -> Result<(), amethyst::Error> {
   let app_root = application_root_dir();
   let assets_path = format!("{}/assets/", app_root);
   let binding_path = format!("{}/resources/bindings_config.ron", app_root);
@@ -87,6 +125,27 @@ pub fn run() -> Result<(), amethyst::Error> {
   //Custom create log to silence "Created buffer" spam every frame
   create_logger(game_config.log_level);
 
+  let rng = GameRng::new(seed.or(game_config.seed));
+
+  let (progress, level) = if let Some(ref replay) = replay {
+    //Playing back a replay shouldn't touch the save file. It also skips the menu and loads straight away
+    let level = Level {
+      current_level: replay.start_level,
+      ..Level::default()
+    };
+    (Progress::default(), level)
+  } else {
+    //The menu starts on the furthest unlocked level, start_level unlocks everything up to it
+    let progress = Progress::load_or_default();
+    let furthest = progress.furthest_unlocked(game_config.levels.levels.len(), game_config.levels.start_level);
+    let level = Level {
+      current_level: furthest,
+      load_state: LoadState::Idle,
+      ..Level::default()
+    };
+    (progress, level)
+  };
+
   let display_config = DisplayConfig::load(&format!("{}/resources/display_config.ron", app_root));
 
   //TODO: Clean up this mess. The configure_rendering and register_systems functions are really fragile
@@ -95,7 +154,7 @@ pub fn run() -> Result<(), amethyst::Error> {
       .with_bindings_from_file(&binding_path)?)?;
 
   let game_data = configure_rendering(
-    register_systems(game_data)?, display_config)?
+    register_systems(game_data, replay)?, display_config)?
     .with(PrefabLoaderSystem::<RunningPrefabData>::default(), "", &[]);
 
 
@@ -107,6 +166,9 @@ pub fn run() -> Result<(), amethyst::Error> {
     .with_resource(game_config.sound)
     .with_resource(game_config.sprites)
     .with_resource(game_config.levels)
+    .with_resource(rng)
+    .with_resource(progress)
+    .with_resource(level)
     .build(game_data)?;
 
   game.run();
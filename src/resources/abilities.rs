@@ -0,0 +1,109 @@
+use ::config::AbilityLimits;
+
+use super::Command;
+
+///Abilities the player can use on the matriarch that can be limited per level
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Ability {
+  Lift,
+  DirectionChanger,
+  Ram,
+  Blocker,
+  Bridge,
+  Dig,
+  Floater,
+  Bomb,
+  Cube,
+}
+
+impl Ability {
+  ///The ability a command uses, if any
+  pub fn for_command(cmd: &Command) -> Option<Self> {
+    match cmd {
+      &Command::DropLift => Some(Ability::Lift),
+      &Command::DropDirectionChanger => Some(Ability::DirectionChanger),
+      &Command::DropRam => Some(Ability::Ram),
+      &Command::DropBlocker => Some(Ability::Blocker),
+      &Command::BuildBridge => Some(Ability::Bridge),
+      &Command::Dig => Some(Ability::Dig),
+      &Command::DropFloater => Some(Ability::Floater),
+      &Command::DropBomb => Some(Ability::Bomb),
+      &Command::DropCube => Some(Ability::Cube),
+      _ => None,
+    }
+  }
+}
+
+///Resource tracking how many uses of each ability are left in the current level. None means unlimited.
+#[derive(Debug, Clone, Default)]
+pub struct AbilityBudget {
+  pub lifts: Option<u32>,
+  pub direction_changers: Option<u32>,
+  pub rams: Option<u32>,
+  pub blockers: Option<u32>,
+  pub bridges: Option<u32>,
+  pub digs: Option<u32>,
+  pub floaters: Option<u32>,
+  pub bombs: Option<u32>,
+  pub cubes: Option<u32>,
+}
+
+impl AbilityBudget {
+  pub fn new(limits: Option<&AbilityLimits>) -> Self {
+    match limits {
+      Some(limits) => Self {
+        lifts: limits.lifts,
+        direction_changers: limits.direction_changers,
+        rams: limits.rams,
+        blockers: limits.blockers,
+        bridges: limits.bridges,
+        digs: limits.digs,
+        floaters: limits.floaters,
+        bombs: limits.bombs,
+        cubes: limits.cubes,
+      },
+      None => Self::default(),
+    }
+  }
+
+  pub fn remaining(&self, ability: Ability) -> Option<u32> {
+    match ability {
+      Ability::Lift => self.lifts,
+      Ability::DirectionChanger => self.direction_changers,
+      Ability::Ram => self.rams,
+      Ability::Blocker => self.blockers,
+      Ability::Bridge => self.bridges,
+      Ability::Dig => self.digs,
+      Ability::Floater => self.floaters,
+      Ability::Bomb => self.bombs,
+      Ability::Cube => self.cubes,
+    }
+  }
+
+  pub fn available(&self, ability: Ability) -> bool {
+    self.remaining(ability).map_or(true, |r| r > 0)
+  }
+
+  ///Uses up one of the ability. Returns false (and uses nothing) if there are none left
+  pub fn use_ability(&mut self, ability: Ability) -> bool {
+    let remaining = match ability {
+      Ability::Lift => &mut self.lifts,
+      Ability::DirectionChanger => &mut self.direction_changers,
+      Ability::Ram => &mut self.rams,
+      Ability::Blocker => &mut self.blockers,
+      Ability::Bridge => &mut self.bridges,
+      Ability::Dig => &mut self.digs,
+      Ability::Floater => &mut self.floaters,
+      Ability::Bomb => &mut self.bombs,
+      Ability::Cube => &mut self.cubes,
+    };
+    match remaining {
+      Some(0) => false,
+      Some(r) => {
+        *r -= 1;
+        true
+      },
+      None => true,
+    }
+  }
+}
@@ -1,20 +1,34 @@
 use amethyst::shrev::EventChannel;
 
 ///Commands that various systems listen for. Most are user input.
-#[derive(Debug)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum Command {
   DropCube,
   DropLift,
   DropDirectionChanger,
   DropRam,
+  DropBlocker,
+  BuildBridge,
+  //Kills the matriarch itself, only if there was something to dig
+  Dig,
+  DropFloater,
+  DropBomb,
   KillMatriarch,
   //-1 to 1 based off the user input axis value
   Zoom(f32),
   ReloadLevels,
+  //Sent by the level watcher when one of the current level's files changes. Reloads like ReloadLevels but isn't recorded
+  FileChanged,
   NextLevel,
   RestartLevel,
   PreviousLevel,
   Exodus,
+  //Sent by the menu, only acted on when no level is loaded
+  StartLevel(usize),
+  //Unloads the level and goes back to the menu
+  ExitLevel,
+  SpeedUp,
+  SlowDown,
 }
 
 ///Does the specified command also kill the matriarch?
@@ -25,13 +39,23 @@ pub fn also_kills(cmd: &Command) -> bool {
     &Command::DropLift => true,
     &Command::DropDirectionChanger => true,
     &Command::DropRam => true,
+    &Command::DropBlocker => true,
+    &Command::BuildBridge => true,
+    &Command::Dig => false,
+    &Command::DropFloater => true,
+    &Command::DropBomb => true,
     &Command::KillMatriarch => false,
     &Command::Zoom(_) => false,
     &Command::ReloadLevels => false,
+    &Command::FileChanged => false,
     &Command::NextLevel => false,
     &Command::RestartLevel => false,
     &Command::PreviousLevel => false,
     &Command::Exodus => false,
+    &Command::StartLevel(_) => false,
+    &Command::ExitLevel => false,
+    &Command::SpeedUp => false,
+    &Command::SlowDown => false,
   }
 }
 
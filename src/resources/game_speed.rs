@@ -0,0 +1,43 @@
+use amethyst::core::Time;
+
+const MIN_SCALE: f32 = 0.25;
+const MAX_SCALE: f32 = 4.0;
+//Caps how far the simulation can move in one frame, enough for 4x game speed at 30fps. Without this a slow
+//frame at a high game speed queues up more physics steps than the next frame can do and it never catches up
+const MAX_DELTA: f32 = 8.0 / 60.0;
+
+///Resource that controls how fast the simulation runs. Systems that should stop when the game is paused take their delta from here rather than Time.
+#[derive(Debug, Clone)]
+pub struct GameSpeed {
+  pub paused: bool,
+  //Multiplier on the frame delta, always a power of 2 between MIN_SCALE and MAX_SCALE
+  pub scale: f32,
+}
+
+impl Default for GameSpeed {
+  fn default() -> Self {
+    Self {
+      paused: false,
+      scale: 1.0,
+    }
+  }
+}
+
+impl GameSpeed {
+  ///Frame delta in seconds scaled by the game speed and capped at MAX_DELTA, 0 while paused
+  pub fn delta_seconds(&self, time: &Time) -> f32 {
+    if self.paused {
+      0.0
+    } else {
+      (time.delta_seconds() * self.scale).min(MAX_DELTA)
+    }
+  }
+
+  pub fn speed_up(&mut self) {
+    self.scale = (self.scale * 2.0).min(MAX_SCALE);
+  }
+
+  pub fn slow_down(&mut self) {
+    self.scale = (self.scale * 0.5).max(MIN_SCALE);
+  }
+}
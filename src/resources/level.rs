@@ -5,12 +5,21 @@ use ::config::{
 
 #[derive(PartialEq)]
 pub enum LoadState {
+  //Nothing loaded, waiting for the menu to start a level
+  Idle,
   NeedConfig,
   NeedLoad,
   Loaded,
   PhysicsCleanup,
 }
 
+///How a level ended
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LevelOutcome {
+  Complete,
+  Failed,
+}
+
 ///Resource that holds the list of levels and tracks which one is loaded
 pub struct Level {
   pub current_level: usize,
@@ -18,6 +27,14 @@ pub struct Level {
   pub prev_camera_settings: Option<CameraOverrides>,
   pub load_state: LoadState,
   pub runtime: f32,
+  //Physics step the current level was loaded on
+  pub load_step: u64,
+  //Set once enough creeps have been saved to win
+  pub cleared: bool,
+  //Set once every creep has been spawned and saved or killed
+  pub ended: bool,
+  //Set at the same time as ended, taken by RunningState to show the results
+  pub outcome: Option<LevelOutcome>,
 }
 
 impl Default for Level {
@@ -28,6 +45,10 @@ impl Default for Level {
       prev_camera_settings: None,
       load_state: LoadState::NeedConfig,
       runtime: 0.0,
+      load_step: 0,
+      cleared: false,
+      ended: false,
+      outcome: None,
     }
   }
 }
\ No newline at end of file
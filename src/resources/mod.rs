@@ -4,10 +4,20 @@ mod spawn_stats;
 mod sound;
 mod sprites;
 mod level;
+mod replay;
+mod rng;
+mod progress;
+mod game_speed;
+mod abilities;
 
 pub use self::physics::*;
 pub use self::commands::*;
 pub use self::spawn_stats::*;
 pub use self::sound::*;
 pub use self::sprites::*;
-pub use self::level::*;
\ No newline at end of file
+pub use self::level::*;
+pub use self::replay::*;
+pub use self::rng::*;
+pub use self::progress::*;
+pub use self::game_speed::*;
+pub use self::abilities::*;
\ No newline at end of file
@@ -10,6 +10,7 @@ use amethyst::{
 use nphysics2d::{
   object::{
     BodyHandle,
+    BodyStatus,
     Material,
     ColliderHandle,
   },
@@ -19,6 +20,7 @@ use nphysics2d::{
 
 use nalgebra::{
   Isometry2,
+  Point2,
   Vector2,
 };
 
@@ -26,9 +28,13 @@ use ncollide2d::{
   events::ContactEvent,
   query::Proximity,
   shape::{
+    Ball,
+    ConvexPolygon,
     Cuboid,
+    Polyline,
     ShapeHandle,
   },
+  world::CollisionGroups,
 };
 
 use ::components::{
@@ -45,6 +51,44 @@ pub const MARGIN: FSize = 0.05 * SCALE_METERS_PER_PIXEL;
 
 const TIMESTEP: f32 = 1.0/60.0;
 
+///Surface and mass properties of a collider
+#[derive(Debug, Clone, Copy)]
+pub struct BodyMaterial {
+  pub friction: FSize,
+  //Bounciness, 0 doesn't bounce at all
+  pub restitution: FSize,
+  //Only used by bodies that can move
+  pub density: FSize,
+}
+
+impl Default for BodyMaterial {
+  //Same as nphysics' defaults
+  fn default() -> Self {
+    Self {
+      friction: 0.5,
+      restitution: 0.0,
+      density: 1.0,
+    }
+  }
+}
+
+impl BodyMaterial {
+  fn collider_material(&self) -> Material<FSize> {
+    Material::new(self.restitution, self.friction)
+  }
+}
+
+///Collider shapes other than boxes. Sizes and points are in pixels, points are relative to the collider's position
+#[derive(Debug, Clone)]
+pub enum ColliderShape {
+  //Radius
+  Ball(FSize),
+  //The convex hull of the points is used
+  ConvexPolygon(Vec<CVector2<FSize>>),
+  //Connected line segments. Has no mass so only works for ground colliders
+  Polyline(Vec<CVector2<FSize>>),
+}
+
 ///Resource that contains the nphysics world and manages collisions.
 //It's a bit jankey but nphysics has a project underway to integrate it properly with specs
 // which will replace this stuff.
@@ -52,6 +96,10 @@ pub struct PhysicsWorld {
   pub world: World<FSize>,
   time_accumulator: f32,
   timestep: f32,
+  //Number of steps taken since creation. Used as the clock for replays
+  step_count: u64,
+  //Number of steps taken since add_time was last called
+  frame_steps: u32,
   collider_entity_map: HashMap<ColliderHandle, Entity>,
   collider_body_map: HashMap<ColliderHandle, BodyHandle>,
   collider_contacts: HashMap<ColliderHandle, Vec<ColliderHandle>>,
@@ -70,6 +118,8 @@ impl PhysicsWorld {
       world: World::new(),
       time_accumulator: 0.0,
       timestep: 0.0,
+      step_count: 0,
+      frame_steps: 0,
       collider_entity_map: HashMap::new(),
       collider_body_map: HashMap::new(),
       collider_contacts: HashMap::new(),
@@ -89,6 +139,20 @@ impl PhysicsWorld {
     self.time_accumulator / self.timestep
   }
 
+  pub fn get_step_count(&self) -> u64 {
+    self.step_count
+  }
+
+  ///Simulated seconds covered by the steps taken this frame. Game clocks use this rather than the frame time so
+  ///they stay in step with the physics (and with replays)
+  pub fn get_frame_delta(&self) -> f32 {
+    self.frame_steps as f32 * self.timestep
+  }
+
+  pub fn get_timestep(&self) -> f32 {
+    self.timestep
+  }
+
   pub fn register_entity(&mut self, entity: Entity, collider_handle: ColliderHandle) {
     debug!("Collider {:?} was associated with entity {:?}", collider_handle, entity);
     self.collider_entity_map.insert(collider_handle, entity);
@@ -105,7 +169,6 @@ impl PhysicsWorld {
     self.collider_body_map.get(collider_handle)
   }
 
-  #[allow(dead_code)]
   pub fn get_contacts(&self, collider_handle: &ColliderHandle) -> Option<&[ColliderHandle]> {
     if let Some(contacts) = self.collider_contacts.get(collider_handle) {
       Some(&contacts)
@@ -168,18 +231,26 @@ impl PhysicsWorld {
 
   fn do_step(&mut self) {
     self.world.step();
+    self.step_count += 1;
+    self.frame_steps += 1;
     self.process_contacts();
     self.process_proximity();
   }
 
-  ///Adds time to the physics world, doesn't perform any steps
+  ///Adds time to the physics world, doesn't perform any steps. Called once at the start of each frame
   pub fn add_time(&mut self, delta: f32) {
     self.time_accumulator += delta;
+    self.frame_steps = 0;
+  }
+
+  ///Is there enough time in the accumulator for a step
+  pub fn step_ready(&self) -> bool {
+    self.time_accumulator >= self.timestep
   }
 
   ///Steps the simulation if there is enough time in the accumulator
   pub fn step(&mut self) -> bool {
-    if self.time_accumulator >= self.timestep {
+    if self.step_ready() {
       self.time_accumulator -= self.timestep;
       self.do_step();
       true
@@ -189,11 +260,20 @@ impl PhysicsWorld {
   }
 
   pub fn create_ground_box_collider(&mut self, pos: &CVector2<FSize>, size: &CVector2<FSize>, rotation: FSize) -> Collider {
-    let shape = ShapeHandle::new(Cuboid::new(Vector2::new(
-      //These are half extents
-      size.x * 0.5 * SCALE_METERS_PER_PIXEL - MARGIN,
-      size.y * 0.5 * SCALE_METERS_PER_PIXEL - MARGIN,
-    )));
+    self.create_ground_box_collider_with_material(pos, size, rotation, &BodyMaterial::default())
+  }
+
+  pub fn create_ground_box_collider_with_material(&mut self, pos: &CVector2<FSize>, size: &CVector2<FSize>, rotation: FSize, material: &BodyMaterial) -> Collider {
+    self.add_ground_collider(box_shape(size), pos, rotation, material)
+  }
+
+  ///None if the shape's points don't make a valid shape
+  pub fn create_ground_collider_with_shape(&mut self, pos: &CVector2<FSize>, shape: &ColliderShape, rotation: FSize, material: &BodyMaterial) -> Option<Collider> {
+    let shape = shape_handle(shape)?;
+    Some(self.add_ground_collider(shape, pos, rotation, material))
+  }
+
+  fn add_ground_collider(&mut self, shape: ShapeHandle<FSize>, pos: &CVector2<FSize>, rotation: FSize, material: &BodyMaterial) -> Collider {
     let to_parent = Isometry2::new(Vector2::new(
       pos.x * SCALE_METERS_PER_PIXEL,
       pos.y * SCALE_METERS_PER_PIXEL),
@@ -204,7 +284,7 @@ impl PhysicsWorld {
       shape,
       BodyHandle::ground(),
       to_parent,
-      Material::default(),
+      material.collider_material(),
     );
     debug!("Created (ground) collider: {:?}", collider_handle);
 
@@ -239,15 +319,31 @@ impl PhysicsWorld {
   }
 
   pub fn create_rigid_body_with_box_collider(&mut self, pos: &CVector2<FSize>, size: &CVector2<FSize>, rotation: FSize) -> Collider {
-    self.create_rigid_body_with_box_collider_with_density(pos, size, rotation, 1.0)
+    self.create_rigid_body_with_box_collider_with_material(pos, size, rotation, &BodyMaterial::default())
   }
 
   pub fn create_rigid_body_with_box_collider_with_density(&mut self, pos: &CVector2<FSize>, size: &CVector2<FSize>, rotation: FSize, density: FSize) -> Collider {
-    let shape = ShapeHandle::new(Cuboid::new(Vector2::new(
-      //These are half extents
-      size.x * 0.5 * SCALE_METERS_PER_PIXEL - MARGIN,
-      size.y * 0.5 * SCALE_METERS_PER_PIXEL - MARGIN,
-    )));
+    let material = BodyMaterial {
+      density,
+      ..BodyMaterial::default()
+    };
+    self.create_rigid_body_with_box_collider_with_material(pos, size, rotation, &material)
+  }
+
+  pub fn create_rigid_body_with_box_collider_with_material(&mut self, pos: &CVector2<FSize>, size: &CVector2<FSize>, rotation: FSize, material: &BodyMaterial) -> Collider {
+    self.add_rigid_body(box_shape(size), pos, rotation, material)
+  }
+
+  ///None if the shape's points don't make a valid shape or it's a polyline (which has no mass)
+  pub fn create_rigid_body_with_shape(&mut self, pos: &CVector2<FSize>, shape: &ColliderShape, rotation: FSize, material: &BodyMaterial) -> Option<Collider> {
+    if let ColliderShape::Polyline(_) = shape {
+      return None;
+    }
+    let shape = shape_handle(shape)?;
+    Some(self.add_rigid_body(shape, pos, rotation, material))
+  }
+
+  fn add_rigid_body(&mut self, shape: ShapeHandle<FSize>, pos: &CVector2<FSize>, rotation: FSize, material: &BodyMaterial) -> Collider {
     let to_parent = Isometry2::identity();
     let pos = Isometry2::new(Vector2::new(
       pos.x * SCALE_METERS_PER_PIXEL,
@@ -255,7 +351,7 @@ impl PhysicsWorld {
       -rotation, //Rotation is not the way you'd think, presumably because this is to_parent not from_parent
     );
 
-    let body_handle = self.world.add_rigid_body(pos, shape.inertia(density), shape.center_of_mass());
+    let body_handle = self.world.add_rigid_body(pos, shape.inertia(material.density), shape.center_of_mass());
     debug!("Created body: {:?}", body_handle);
 
     let collider_handle = self.world.add_collider(
@@ -263,7 +359,7 @@ impl PhysicsWorld {
       shape,
       body_handle,
       to_parent,
-      Material::default(),
+      material.collider_material(),
     );
     debug!("Created collider: {:?}", collider_handle);
 
@@ -272,25 +368,66 @@ impl PhysicsWorld {
     Collider::new(body_handle, collider_handle)
   }
 
-  ///Destroy a collider (also destroys the body if no colliders remain... this may not be what you want in all cases but it's convenient for now)
-  pub fn destroy_collider(&mut self, collider: Collider) {
-    //Wake up any things this is touching before destroying it (bug in nphysics https://github.com/rustsim/nphysics/issues/154)
-    if let Some(contacts) = self.collider_contacts.remove(&collider.collider_handle) {
+  ///Body that only moves at the velocity it's given, nothing pushes it around
+  pub fn create_kinematic_box_collider(&mut self, pos: &CVector2<FSize>, size: &CVector2<FSize>, rotation: FSize) -> Collider {
+    let collider = self.create_rigid_body_with_box_collider(pos, size, rotation);
+    if let Some(body) = self.world.rigid_body_mut(collider.body_handle) {
+      body.set_status(BodyStatus::Kinematic);
+    }
+    collider
+  }
+
+  ///Moves a body straight to a new position (in pixels) without changing its rotation
+  pub fn set_body_position(&mut self, body_handle: BodyHandle, pos: &CVector2<FSize>) {
+    self.world.activate_body(body_handle);
+    if let Some(body) = self.world.rigid_body_mut(body_handle) {
+      let rotation = body.position().rotation.angle();
+      body.set_position(Isometry2::new(Vector2::new(
+        pos.x * SCALE_METERS_PER_PIXEL,
+        pos.y * SCALE_METERS_PER_PIXEL),
+        rotation,
+      ));
+    }
+  }
+
+  ///Turns collisions for a collider on or off without destroying it
+  pub fn set_collider_enabled(&mut self, collider_handle: ColliderHandle, enabled: bool) {
+    let mut groups = CollisionGroups::new();
+    if !enabled {
+      //Nothing can interact with something that has an empty whitelist
+      groups.set_whitelist(&[]);
+      self.forget_contacts(&collider_handle);
+    }
+    self
+      .world
+      .collision_world_mut()
+      .set_collision_groups(collider_handle, groups);
+  }
+
+  //Removes the contacts and proximities of a collider that's going away (or stopping colliding)
+  fn forget_contacts(&mut self, collider_handle: &ColliderHandle) {
+    //Wake up any things this is touching before it goes (bug in nphysics https://github.com/rustsim/nphysics/issues/154)
+    if let Some(contacts) = self.collider_contacts.remove(collider_handle) {
       for c in contacts {
         self.world.activate_body(
           *self.collider_body_map.get(&c).expect("Collider missing from body map"));
 
         //I thought you'd get Stopped events after the deletion but you don't
-        remove_contact(&mut self.collider_contacts, &c, &collider.collider_handle);
+        remove_contact(&mut self.collider_contacts, &c, collider_handle);
       }
     }
 
-    if let Some(prox) = self.collider_proximity.remove(&collider.collider_handle) {
+    if let Some(prox) = self.collider_proximity.remove(collider_handle) {
       for p in prox {
         //I thought you'd get Disjoint events after the deletion but you don't
-        remove_contact(&mut self.collider_proximity, &p, &collider.collider_handle);
+        remove_contact(&mut self.collider_proximity, &p, collider_handle);
       }
     }
+  }
+
+  ///Destroy a collider (also destroys the body if no colliders remain... this may not be what you want in all cases but it's convenient for now)
+  pub fn destroy_collider(&mut self, collider: Collider) {
+    self.forget_contacts(&collider.collider_handle);
 
     //Clean up the link to an entity
     self.collider_entity_map.remove(&collider.collider_handle);
@@ -336,4 +473,40 @@ fn remove_contact(map: &mut HashMap<ColliderHandle, Vec<ColliderHandle>>, c1: &C
   if remove {
     map.remove(c1);
   }
+}
+
+fn box_shape(size: &CVector2<FSize>) -> ShapeHandle<FSize> {
+  ShapeHandle::new(Cuboid::new(Vector2::new(
+    //These are half extents
+    size.x * 0.5 * SCALE_METERS_PER_PIXEL - MARGIN,
+    size.y * 0.5 * SCALE_METERS_PER_PIXEL - MARGIN,
+  )))
+}
+
+fn shape_handle(shape: &ColliderShape) -> Option<ShapeHandle<FSize>> {
+  let to_points = |points: &[CVector2<FSize>]| points
+    .iter()
+    .map(|p| Point2::new(p.x * SCALE_METERS_PER_PIXEL, p.y * SCALE_METERS_PER_PIXEL))
+    .collect::<Vec<_>>();
+
+  match shape {
+    ColliderShape::Ball(radius) => {
+      //Shrunk by the margin like boxes are
+      let radius = radius * SCALE_METERS_PER_PIXEL - MARGIN;
+      if radius > 0.0 {
+        Some(ShapeHandle::new(Ball::new(radius)))
+      } else {
+        None
+      }
+    },
+    ColliderShape::ConvexPolygon(points) =>
+      ConvexPolygon::try_from_points(&to_points(points)).map(ShapeHandle::new),
+    ColliderShape::Polyline(points) => {
+      if points.len() >= 2 {
+        Some(ShapeHandle::new(Polyline::new(to_points(points))))
+      } else {
+        None
+      }
+    },
+  }
 }
\ No newline at end of file
@@ -0,0 +1,90 @@
+use std::{
+  collections::BTreeMap,
+  path::Path,
+};
+
+use amethyst::{
+  prelude::Config,
+  utils::application_root_dir,
+};
+
+const SAVE_FILE: &'static str = "save.ron";
+
+///Best results the player has got on a level
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct LevelResult {
+  pub best_saved_ratio: f32,
+  //These are None until the level has been cleared
+  pub fastest_clear: Option<f32>,
+  pub fewest_abilities: Option<u32>,
+}
+
+///Resource holding the players results for each level (by index). Persisted to save.ron
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Progress {
+  pub levels: BTreeMap<usize, LevelResult>,
+  //Not set when running headless so test runs don't touch the players save
+  #[serde(skip)]
+  save_path: Option<String>,
+}
+
+impl Progress {
+  ///Loads the save file, starting from scratch if there isn't one
+  pub fn load_or_default() -> Self {
+    let save_path = format!("{}/{}", application_root_dir(), SAVE_FILE);
+    let mut progress = if Path::new(&save_path).exists() {
+      match Progress::load_no_fallback(&save_path) {
+        Ok(progress) => progress,
+        Err(e) => {
+          error!("Error loading save file, starting from scratch: {}", e);
+          Progress::default()
+        },
+      }
+    } else {
+      Progress::default()
+    };
+    progress.save_path = Some(save_path);
+    progress
+  }
+
+  pub fn save(&self) {
+    if let Some(ref save_path) = self.save_path {
+      debug!("Saving progress to {}", save_path);
+      if let Err(e) = self.write(save_path) {
+        error!("Error saving progress: {}", e);
+      }
+    }
+  }
+
+  pub fn is_cleared(&self, level: usize) -> bool {
+    self
+      .levels
+      .get(&level)
+      .map_or(false, |r| r.fastest_clear.is_some())
+  }
+
+  ///Levels up to the config's start_level (or just the first if it isn't set) are always unlocked, the rest need
+  ///the previous level to be cleared
+  pub fn is_unlocked(&self, level: usize, start_level: Option<usize>) -> bool {
+    level <= start_level.unwrap_or(0) || self.is_cleared(level - 1)
+  }
+
+  ///The last level the player can play out of level_count levels
+  pub fn furthest_unlocked(&self, level_count: usize, start_level: Option<usize>) -> usize {
+    (0..level_count)
+      .take_while(|&l| self.is_unlocked(l, start_level))
+      .last()
+      .unwrap_or(0)
+  }
+
+  pub fn record_saved_ratio(&mut self, level: usize, saved_ratio: f32) {
+    let result = self.levels.entry(level).or_insert(LevelResult::default());
+    result.best_saved_ratio = result.best_saved_ratio.max(saved_ratio);
+  }
+
+  pub fn record_clear(&mut self, level: usize, time: f32, abilities: u32) {
+    let result = self.levels.entry(level).or_insert(LevelResult::default());
+    result.fastest_clear = Some(result.fastest_clear.map_or(time, |t| t.min(time)));
+    result.fewest_abilities = Some(result.fewest_abilities.map_or(abilities, |a| a.min(abilities)));
+  }
+}
@@ -0,0 +1,26 @@
+use amethyst::utils::application_root_dir;
+
+use super::Command;
+
+const REPLAY_FILE: &'static str = "replay.ron";
+
+///A command and the physics step it was sent on
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReplayEvent {
+  //Counted from when the first level was loaded
+  pub step: u64,
+  pub command: Command,
+}
+
+///Resource that holds every command sent so far so the run can be played back.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Replay {
+  pub start_level: usize,
+  pub seed: u64,
+  pub events: Vec<ReplayEvent>,
+}
+
+///Where the replay of the last run gets written
+pub fn replay_path() -> String {
+  format!("{}/{}", application_root_dir(), REPLAY_FILE)
+}
@@ -0,0 +1,49 @@
+use rand::{
+  self,
+  Error,
+  RngCore,
+  SeedableRng,
+  rngs::StdRng,
+};
+
+///Resource that all randomness in the game should come from. Runs with the same seed produce the same output.
+pub struct GameRng {
+  pub seed: u64,
+  rng: StdRng,
+}
+
+impl Default for GameRng {
+  fn default() -> Self {
+    Self::new(None)
+  }
+}
+
+impl GameRng {
+  ///Picks a seed at random if one isn't provided
+  pub fn new(seed: Option<u64>) -> Self {
+    let seed = seed.unwrap_or_else(|| rand::random());
+    info!("Using RNG seed: {}", seed);
+    Self {
+      seed,
+      rng: StdRng::seed_from_u64(seed),
+    }
+  }
+}
+
+impl RngCore for GameRng {
+  fn next_u32(&mut self) -> u32 {
+    self.rng.next_u32()
+  }
+
+  fn next_u64(&mut self) -> u64 {
+    self.rng.next_u64()
+  }
+
+  fn fill_bytes(&mut self, dest: &mut [u8]) {
+    self.rng.fill_bytes(dest)
+  }
+
+  fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+    self.rng.try_fill_bytes(dest)
+  }
+}
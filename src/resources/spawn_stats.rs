@@ -1,11 +1,13 @@
 ///Resource that tracks how many creeps have been spawned/died/saved/etc.
-#[derive(Default)]
+#[derive(Default, Debug, Clone)]
 pub struct SpawnStats {
   pub total: u32,
   pub spawned: u32,
   pub killed: u32,
   pub saved: u32,
   pub win_ratio: f32,
+  //How many times the player has used an ability (lift, ram, etc.)
+  pub abilities_used: u32,
 }
 
 impl SpawnStats {
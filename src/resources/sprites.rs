@@ -27,6 +27,7 @@ const SPRITESHEET_FILE: &'static str = "spritesheet.png";
 
 const LIFT_SPRITE_NAME: &'static str = "lift";
 const CHANGE_DIRECTION_SPRITE_NAME: &'static str = "change_direction";
+const CUBE_SPRITE_NAME: &'static str = "cube";
 
 // `texture_id` is a application defined ID given to the texture to store in the `World`.
 // This is needed to link the texture to the sprite_sheet.
@@ -36,6 +37,7 @@ const TEXTURE_ID: u64 = 0;
 pub struct Sprites {
   pub lift: SpriteRender,
   pub change_direction: SpriteRender,
+  pub cube: SpriteRender,
 }
 
 fn find_and_load_named_sprite(name: &str, sprites_config: &SpritesConfig) -> Sprite {
@@ -90,16 +92,17 @@ impl Sprites {
       material_texture_set.insert(TEXTURE_ID, texture_handle);
     }
 
-    let (lift_sprite, cd_sprite) = {
+    let (lift_sprite, cd_sprite, cube_sprite) = {
       let sprites_config = world.read_resource::<SpritesConfig>();
       let lift_sprite = find_and_load_named_sprite(LIFT_SPRITE_NAME, &sprites_config);
       let cd_sprite = find_and_load_named_sprite(CHANGE_DIRECTION_SPRITE_NAME, &sprites_config);
-      (lift_sprite, cd_sprite)
+      let cube_sprite = find_and_load_named_sprite(CUBE_SPRITE_NAME, &sprites_config);
+      (lift_sprite, cd_sprite, cube_sprite)
     };
 
     let sprite_sheet = SpriteSheet {
       texture_id: TEXTURE_ID,
-      sprites: vec![lift_sprite, cd_sprite],
+      sprites: vec![lift_sprite, cd_sprite, cube_sprite],
     };
 
     let sprite_count = sprite_sheet.sprites.len();
@@ -124,15 +127,24 @@ impl Sprites {
       flip_vertical: false,
     };
 
+    let cube_render = SpriteRender {
+      sprite_sheet: sprite_sheet_handle.clone(),
+      sprite_number: 2,
+      flip_horizontal: false,
+      flip_vertical: false,
+    };
+
 
     //These are just to check for typos in sprite_number values above
     //TODO: a better way of registering sprites and tracking their number
     assert!(lift_render.sprite_number < sprite_count);
     assert!(cd_render.sprite_number < sprite_count);
+    assert!(cube_render.sprite_number < sprite_count);
 
     Sprites {
       lift: lift_render,
       change_direction: cd_render,
+      cube: cube_render,
     }
   }
 }
\ No newline at end of file
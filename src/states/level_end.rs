@@ -0,0 +1,206 @@
+use amethyst::{
+  ecs::prelude::*,
+  prelude::*,
+  ui::{
+    UiCreator,
+    UiEventType,
+    UiFinder,
+    UiText,
+  },
+};
+
+use ::config::LevelsConfig;
+use ::resources::{
+  Command,
+  CommandChannel,
+  Level,
+  LoadState,
+  Progress,
+  SpawnStats,
+};
+
+use super::ui::{
+  delete_ui,
+  set_ui_hidden,
+};
+
+const RESULTS_UI: &'static str = "ui/results.ron";
+
+///Pushed on top of RunningState when enough creeps were saved and the level has ended.
+pub struct LevelCompleteState {
+  screen: ResultsScreen,
+}
+
+///Pushed on top of RunningState when too few creeps were saved and the level has ended.
+pub struct LevelFailedState {
+  screen: ResultsScreen,
+}
+
+impl Default for LevelCompleteState {
+  fn default() -> Self {
+    Self {
+      screen: ResultsScreen::new("LEVEL COMPLETE"),
+    }
+  }
+}
+
+impl Default for LevelFailedState {
+  fn default() -> Self {
+    Self {
+      screen: ResultsScreen::new("LEVEL FAILED"),
+    }
+  }
+}
+
+impl<'a, 'b> SimpleState<'a, 'b> for LevelCompleteState {
+  fn on_start(&mut self, data: StateData<GameData>) {
+    info!("LevelCompleteState.on_start");
+    self.screen.start(data.world);
+  }
+  fn on_stop(&mut self, data: StateData<GameData>) {
+    self.screen.stop(data.world);
+  }
+  fn handle_event(&mut self, data: StateData<GameData>, event: StateEvent) -> SimpleTrans<'a, 'b> {
+    self.screen.handle_event(data.world, &event)
+  }
+  fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans<'a, 'b> {
+    self.screen.update(data.world)
+  }
+}
+
+impl<'a, 'b> SimpleState<'a, 'b> for LevelFailedState {
+  fn on_start(&mut self, data: StateData<GameData>) {
+    info!("LevelFailedState.on_start");
+    self.screen.start(data.world);
+  }
+  fn on_stop(&mut self, data: StateData<GameData>) {
+    self.screen.stop(data.world);
+  }
+  fn handle_event(&mut self, data: StateData<GameData>, event: StateEvent) -> SimpleTrans<'a, 'b> {
+    self.screen.handle_event(data.world, &event)
+  }
+  fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans<'a, 'b> {
+    self.screen.update(data.world)
+  }
+}
+
+///The results ui shared by the level end states. Shows how the level went and offers retry/next/menu.
+struct ResultsScreen {
+  title: &'static str,
+  //Taken when the screen opens, the level keeps running underneath
+  lines: Vec<(&'static str, String)>,
+  show_next: bool,
+  root: Option<Entity>,
+  retry_button: Option<Entity>,
+  next_button: Option<Entity>,
+  menu_button: Option<Entity>,
+  populated: bool,
+}
+
+impl ResultsScreen {
+  fn new(title: &'static str) -> Self {
+    Self {
+      title,
+      lines: Vec::new(),
+      show_next: false,
+      root: None,
+      retry_button: None,
+      next_button: None,
+      menu_button: None,
+      populated: false,
+    }
+  }
+
+  fn start(&mut self, world: &mut World) {
+    {
+      let level = world.read_resource::<Level>();
+      let spawn_stats = world.read_resource::<SpawnStats>();
+      let progress = world.read_resource::<Progress>();
+      let start_level = world.read_resource::<LevelsConfig>().start_level;
+
+      self.lines = vec![
+        ("results_title", self.title.to_string()),
+        ("results_saved", format!("SAVED: {}/{}", spawn_stats.saved, spawn_stats.total)),
+        ("results_killed", format!("KILLED: {}", spawn_stats.killed)),
+        ("results_needed", format!("% NEEDED: {:.*} (SAVED {:.*})", 0, spawn_stats.win_ratio * 100.0, 0, spawn_stats.saved_ratio() * 100.0)),
+        ("results_time", format!("TIME: {:.*}s", 1, level.runtime)),
+        ("results_abilities", format!("ABILITIES USED: {}", spawn_stats.abilities_used)),
+      ];
+
+      let next_level = level.current_level + 1;
+      self.show_next = next_level < level.levels.len() && (level.cleared || progress.is_unlocked(next_level, start_level));
+    }
+
+    self.root = Some(world.exec(|mut creator: UiCreator| {
+      creator.create(RESULTS_UI, ())
+    }));
+  }
+
+  fn stop(&mut self, world: &mut World) {
+    if let Some(root) = self.root.take() {
+      delete_ui(world, root);
+    }
+  }
+
+  fn handle_event<'a, 'b>(&mut self, world: &mut World, event: &StateEvent) -> SimpleTrans<'a, 'b> {
+    if let StateEvent::Ui(event) = event {
+      if event.event_type == UiEventType::Click {
+        let target = Some(event.target);
+        //The level system does the actual work, this state pops itself once the level has changed
+        if target == self.retry_button {
+          world.write_resource::<CommandChannel>().single_write(Command::RestartLevel);
+        } else if target == self.next_button && self.show_next {
+          world.write_resource::<CommandChannel>().single_write(Command::NextLevel);
+        } else if target == self.menu_button {
+          world.write_resource::<CommandChannel>().single_write(Command::ExitLevel);
+        }
+      }
+    }
+    Trans::None
+  }
+
+  fn update<'a, 'b>(&mut self, world: &mut World) -> SimpleTrans<'a, 'b> {
+    //The level was restarted or changed (either from the buttons or the usual keys)
+    if world.read_resource::<Level>().load_state != LoadState::Loaded {
+      return Trans::Pop;
+    }
+
+    //The ui prefab loads asynchronously so keep looking until it's there
+    if !self.populated {
+      self.find_ui_components(world);
+    }
+
+    Trans::None
+  }
+
+  fn find_ui_components(&mut self, world: &mut World) {
+    let mut found = Vec::new();
+    world.exec(|finder: UiFinder| {
+      self.retry_button = self.retry_button.or_else(|| finder.find("retry"));
+      self.next_button = self.next_button.or_else(|| finder.find("next"));
+      self.menu_button = self.menu_button.or_else(|| finder.find("menu"));
+      for (id, _) in &self.lines {
+        found.push(finder.find(id));
+      }
+    });
+
+    if found.iter().any(|e| e.is_none()) || self.next_button.is_none() {
+      return;
+    }
+
+    {
+      let mut ui_text = world.write_storage::<UiText>();
+      for (entity, (_, text)) in found.into_iter().zip(self.lines.iter()) {
+        if let Some(ui_text) = ui_text.get_mut(entity.unwrap()) {
+          ui_text.text = text.clone();
+        }
+      }
+    }
+
+    if !self.show_next {
+      set_ui_hidden(world, self.next_button.unwrap(), true);
+    }
+
+    self.populated = true;
+  }
+}
@@ -30,15 +30,18 @@ use ::{
     PhysicsWorld,
     Sounds,
     Sprites,
+    Level,
+    LoadState,
   }
 };
 
 use super::{
+  MenuState,
   RunningState,
   RunningPrefabData,
 };
 
-///Loads required assets and makes sure everythin is ready before moving to the running state.
+///Loads required assets and makes sure everythin is ready before moving to the menu (or the running state for replays).
 #[derive(Default)]
 pub struct LoadingState {
   progress: ProgressCounter,
@@ -60,7 +63,7 @@ impl<'a, 'b> SimpleState<'a, 'b> for LoadingState {
     self.load_sounds(world);
     self.load_sprites(world);
   }
-  fn update(&mut self, _data: &mut StateData<GameData>) -> SimpleTrans<'a, 'b> {
+  fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans<'a, 'b> {
     if !self.loader_complete {
       match self.progress.complete() {
         Completion::Loading => {},
@@ -81,11 +84,26 @@ impl<'a, 'b> SimpleState<'a, 'b> for LoadingState {
                           .take()
                           .expect("LoadingState.running_prefab_handle was None after loading was finished");
 
-      return Trans::Switch(
-        Box::new(
-          RunningState::new(running_ui, running_prefab)
+      //The scene (camera and lights) sticks around for the whole game, RunningState and MenuState come and go
+      data.world
+        .create_entity()
+        .with(running_prefab)
+        .build();
+
+      //Replays skip the menu and have already started loading their level
+      if data.world.read_resource::<Level>().load_state == LoadState::Idle {
+        return Trans::Switch(
+          Box::new(
+            MenuState::new(running_ui)
+          )
+        )
+      } else {
+        return Trans::Switch(
+          Box::new(
+            RunningState::new(running_ui)
+          )
         )
-      )
+      }
     }
     Trans::None
   }
@@ -0,0 +1,265 @@
+use amethyst::{
+  assets::Handle,
+  ecs::prelude::*,
+  prelude::*,
+  input::is_key_down,
+  ui::{
+    UiCreator,
+    UiEventType,
+    UiFinder,
+    UiPrefab,
+    UiText,
+  },
+  winit::VirtualKeyCode,
+};
+
+use ::{
+  config::LevelsConfig,
+  resources::{
+    Command,
+    CommandChannel,
+    Level,
+    LoadState,
+    Progress,
+  },
+};
+
+use super::{
+  ui::{
+    delete_ui,
+    set_ui_hidden,
+  },
+  OptionsState,
+  RunningState,
+};
+
+const MENU_UI: &'static str = "ui/menu.ron";
+
+///Main menu and level select. Starts the chosen level and switches to RunningState once it's loading.
+pub struct MenuState {
+  running_ui_handle: Handle<UiPrefab>,
+  root: Option<Entity>,
+  selected: usize,
+  level_name_display: Option<Entity>,
+  level_description_display: Option<Entity>,
+  level_status_display: Option<Entity>,
+  prev_button: Option<Entity>,
+  next_button: Option<Entity>,
+  play_button: Option<Entity>,
+  options_button: Option<Entity>,
+  quit_button: Option<Entity>,
+  //Set when the selected level changes so the texts get updated
+  dirty: bool,
+}
+
+impl<'a, 'b> SimpleState<'a, 'b> for MenuState {
+  fn on_start(&mut self, data: StateData<GameData>) {
+    info!("MenuState.on_start");
+    //Starts on the level that was last played (or the furthest unlocked one)
+    self.selected = data.world.read_resource::<Level>().current_level;
+    self.open_ui(data.world);
+  }
+  fn on_stop(&mut self, data: StateData<GameData>) {
+    self.close_ui(data.world);
+  }
+  fn on_pause(&mut self, data: StateData<GameData>) {
+    self.close_ui(data.world);
+  }
+  fn on_resume(&mut self, data: StateData<GameData>) {
+    self.open_ui(data.world);
+  }
+  fn handle_event(&mut self, data: StateData<GameData>, event: StateEvent) -> SimpleTrans<'a, 'b> {
+    let world = data.world;
+    match &event {
+      StateEvent::Window(event) => {
+        if is_key_down(&event, VirtualKeyCode::Escape) {
+          return Trans::Quit;
+        } else if is_key_down(&event, VirtualKeyCode::Left) {
+          self.select_previous();
+        } else if is_key_down(&event, VirtualKeyCode::Right) {
+          self.select_next(world);
+        } else if is_key_down(&event, VirtualKeyCode::Return) {
+          self.play(world);
+        }
+      },
+      StateEvent::Ui(event) => {
+        if event.event_type == UiEventType::Click {
+          let target = Some(event.target);
+          if target == self.prev_button {
+            self.select_previous();
+          } else if target == self.next_button {
+            self.select_next(world);
+          } else if target == self.play_button {
+            self.play(world);
+          } else if target == self.options_button {
+            return Trans::Push(Box::new(OptionsState::default()));
+          } else if target == self.quit_button {
+            return Trans::Quit;
+          }
+        }
+      },
+    }
+    Trans::None
+  }
+  fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans<'a, 'b> {
+    let world = &mut data.world;
+
+    //The level system picked up the StartLevel command
+    if world.read_resource::<Level>().load_state != LoadState::Idle {
+      return Trans::Switch(Box::new(RunningState::new(self.running_ui_handle.clone())));
+    }
+
+    //The ui prefab loads asynchronously so keep looking until it's there
+    if self.quit_button.is_none() {
+      self.find_ui_components(world);
+    }
+
+    if self.dirty && self.quit_button.is_some() {
+      self.update_level_info(world);
+      self.dirty = false;
+    }
+
+    Trans::None
+  }
+}
+
+impl MenuState {
+  pub fn new(running_ui_handle: Handle<UiPrefab>) -> Self {
+    Self {
+      running_ui_handle,
+      root: None,
+      selected: 0,
+      level_name_display: None,
+      level_description_display: None,
+      level_status_display: None,
+      prev_button: None,
+      next_button: None,
+      play_button: None,
+      options_button: None,
+      quit_button: None,
+      dirty: true,
+    }
+  }
+
+  fn open_ui(&mut self, world: &mut World) {
+    self.root = Some(world.exec(|mut creator: UiCreator| {
+      creator.create(MENU_UI, ())
+    }));
+    self.dirty = true;
+  }
+
+  fn close_ui(&mut self, world: &mut World) {
+    if let Some(root) = self.root.take() {
+      delete_ui(world, root);
+    }
+    self.level_name_display = None;
+    self.level_description_display = None;
+    self.level_status_display = None;
+    self.prev_button = None;
+    self.next_button = None;
+    self.play_button = None;
+    self.options_button = None;
+    self.quit_button = None;
+  }
+
+  fn select_previous(&mut self) {
+    if self.selected > 0 {
+      self.selected -= 1;
+      self.dirty = true;
+    }
+  }
+
+  fn select_next(&mut self, world: &mut World) {
+    if self.selected + 1 < world.read_resource::<LevelsConfig>().levels.len() {
+      self.selected += 1;
+      self.dirty = true;
+    }
+  }
+
+  //The level system does the loading, update switches to RunningState once it has started
+  fn play(&self, world: &mut World) {
+    let (start_level, broken) = {
+      let levels_config = world.read_resource::<LevelsConfig>();
+      (levels_config.start_level, levels_config.failed_to_load(self.selected))
+    };
+    if !broken && world.read_resource::<Progress>().is_unlocked(self.selected, start_level) {
+      world.write_resource::<CommandChannel>().single_write(Command::StartLevel(self.selected));
+    }
+  }
+
+  fn update_level_info(&mut self, world: &mut World) {
+    let (texts, playable) = {
+      let levels_config = world.read_resource::<LevelsConfig>();
+      let progress = world.read_resource::<Progress>();
+      let level_count = levels_config.levels.len();
+      self.selected = self.selected.min(level_count.max(1) - 1);
+
+      let (name, description) = match levels_config.levels.get(self.selected) {
+        Some(level) => (
+          level.name.clone().unwrap_or_default(),
+          level.description.clone().unwrap_or_default(),
+        ),
+        None => (String::new(), String::new()),
+      };
+
+      let unlocked = progress.is_unlocked(self.selected, levels_config.start_level);
+      let broken = levels_config.failed_to_load(self.selected);
+      let status = if broken {
+        "FAILED TO LOAD".to_string()
+      } else if !unlocked {
+        "LOCKED".to_string()
+      } else {
+        match progress.levels.get(&self.selected) {
+          Some(result) => match result.fastest_clear {
+            Some(time) => format!("BEST: {:.*}% SAVED, CLEARED IN {:.*}s", 0, result.best_saved_ratio * 100.0, 1, time),
+            None => format!("BEST: {:.*}% SAVED", 0, result.best_saved_ratio * 100.0),
+          },
+          None => "NOT PLAYED".to_string(),
+        }
+      };
+
+      let texts = [
+        (self.level_name_display, format!("LEVEL {}/{}: {}", self.selected + 1, level_count, name)),
+        (self.level_description_display, description),
+        (self.level_status_display, status),
+      ];
+      (texts, unlocked && !broken)
+    };
+
+    {
+      let mut ui_text = world.write_storage::<UiText>();
+      for (entity, text) in texts.iter() {
+        if let Some(ui_text) = ui_text.get_mut(entity.unwrap()) {
+          ui_text.text = text.clone();
+        }
+      }
+    }
+
+    //Locked and broken levels can still be looked at but not played
+    set_ui_hidden(world, self.play_button.unwrap(), !playable);
+  }
+
+  fn find_ui_components(&mut self, world: &mut World) {
+    world.exec(|finder: UiFinder| {
+      self.level_name_display = self.level_name_display.or_else(|| finder.find("menu_level_name"));
+      self.level_description_display = self.level_description_display.or_else(|| finder.find("menu_level_description"));
+      self.level_status_display = self.level_status_display.or_else(|| finder.find("menu_level_status"));
+      self.prev_button = self.prev_button.or_else(|| finder.find("menu_prev"));
+      self.next_button = self.next_button.or_else(|| finder.find("menu_next"));
+      self.play_button = self.play_button.or_else(|| finder.find("menu_play"));
+      self.options_button = self.options_button.or_else(|| finder.find("menu_options"));
+    });
+
+    //quit_button doubles as the "everything was found" flag so it's only set once the rest are there
+    if self.level_name_display.is_some()
+      && self.level_description_display.is_some()
+      && self.level_status_display.is_some()
+      && self.prev_button.is_some()
+      && self.next_button.is_some()
+      && self.play_button.is_some()
+      && self.options_button.is_some()
+    {
+      self.quit_button = world.exec(|finder: UiFinder| finder.find("menu_quit"));
+    }
+  }
+}
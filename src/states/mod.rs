@@ -1,8 +1,20 @@
 mod loading;
+mod menu;
+mod options;
+mod paused;
 mod running;
+mod level_end;
+mod ui;
 
 pub use self::loading::LoadingState;
+pub use self::menu::MenuState;
+pub use self::options::OptionsState;
+pub use self::paused::PausedState;
 pub use self::running::{
   RunningState,
   RunningPrefabData,
-};
\ No newline at end of file
+};
+pub use self::level_end::{
+  LevelCompleteState,
+  LevelFailedState,
+};
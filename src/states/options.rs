@@ -0,0 +1,99 @@
+use amethyst::{
+  ecs::prelude::*,
+  prelude::*,
+  input::is_key_down,
+  ui::{
+    UiCreator,
+    UiEventType,
+    UiFinder,
+    UiText,
+  },
+  winit::VirtualKeyCode,
+};
+
+use ::resources::Sounds;
+
+use super::ui::delete_ui;
+
+const OPTIONS_UI: &'static str = "ui/options.ron";
+const VOLUME_STEP: f32 = 0.1;
+
+///Options screen pushed on top of the menu.
+#[derive(Default)]
+pub struct OptionsState {
+  root: Option<Entity>,
+  volume_display: Option<Entity>,
+  volume_down_button: Option<Entity>,
+  volume_up_button: Option<Entity>,
+  back_button: Option<Entity>,
+  dirty: bool,
+}
+
+impl<'a, 'b> SimpleState<'a, 'b> for OptionsState {
+  fn on_start(&mut self, data: StateData<GameData>) {
+    info!("OptionsState.on_start");
+    self.root = Some(data.world.exec(|mut creator: UiCreator| {
+      creator.create(OPTIONS_UI, ())
+    }));
+    self.dirty = true;
+  }
+  fn on_stop(&mut self, data: StateData<GameData>) {
+    if let Some(root) = self.root.take() {
+      delete_ui(data.world, root);
+    }
+  }
+  fn handle_event(&mut self, data: StateData<GameData>, event: StateEvent) -> SimpleTrans<'a, 'b> {
+    match &event {
+      StateEvent::Window(event) => {
+        if is_key_down(&event, VirtualKeyCode::Escape) {
+          return Trans::Pop;
+        }
+      },
+      StateEvent::Ui(event) => {
+        if event.event_type == UiEventType::Click {
+          let target = Some(event.target);
+          if target == self.volume_down_button {
+            self.change_volume(data.world, -VOLUME_STEP);
+          } else if target == self.volume_up_button {
+            self.change_volume(data.world, VOLUME_STEP);
+          } else if target == self.back_button {
+            return Trans::Pop;
+          }
+        }
+      },
+    }
+    Trans::None
+  }
+  fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans<'a, 'b> {
+    let world = &mut data.world;
+
+    //The ui prefab loads asynchronously so keep looking until it's there
+    if self.back_button.is_none() {
+      world.exec(|finder: UiFinder| {
+        self.volume_display = self.volume_display.or_else(|| finder.find("options_volume"));
+        self.volume_down_button = self.volume_down_button.or_else(|| finder.find("options_volume_down"));
+        self.volume_up_button = self.volume_up_button.or_else(|| finder.find("options_volume_up"));
+        self.back_button = finder.find("options_back");
+      });
+    }
+
+    if self.dirty {
+      let mut ui_text = world.write_storage::<UiText>();
+      if let Some(volume_display) = self.volume_display.and_then(|entity| ui_text.get_mut(entity)) {
+        let volume = world.read_resource::<Sounds>().volume;
+        volume_display.text = format!("VOLUME: {:.*}%", 0, volume * 100.0);
+        self.dirty = false;
+      }
+    }
+
+    Trans::None
+  }
+}
+
+impl OptionsState {
+  fn change_volume(&mut self, world: &mut World, amount: f32) {
+    let mut sounds = world.write_resource::<Sounds>();
+    sounds.volume = (sounds.volume + amount).min(1.0).max(0.0);
+    self.dirty = true;
+  }
+}
@@ -0,0 +1,124 @@
+use amethyst::{
+  ecs::prelude::*,
+  prelude::*,
+  input::is_key_down,
+  ui::{
+    UiCreator,
+    UiEventType,
+    UiFinder,
+  },
+  winit::VirtualKeyCode,
+};
+
+use ::resources::{
+  Command,
+  CommandChannel,
+  GameSpeed,
+};
+
+use super::ui::{
+  delete_ui,
+  set_ui_hidden,
+};
+
+const PAUSED_UI: &'static str = "ui/paused.ron";
+
+///Pushed on top of RunningState. Freezes the simulation and shows resume/restart/quit, quitting has to be confirmed.
+#[derive(Default)]
+pub struct PausedState {
+  root: Option<Entity>,
+  resume_button: Option<Entity>,
+  restart_button: Option<Entity>,
+  quit_button: Option<Entity>,
+  confirm_panel: Option<Entity>,
+  confirm_yes_button: Option<Entity>,
+  confirm_no_button: Option<Entity>,
+  confirming: bool,
+}
+
+impl<'a, 'b> SimpleState<'a, 'b> for PausedState {
+  fn on_start(&mut self, data: StateData<GameData>) {
+    info!("PausedState.on_start");
+    let world = data.world;
+
+    world.write_resource::<GameSpeed>().paused = true;
+    self.root = Some(world.exec(|mut creator: UiCreator| {
+      creator.create(PAUSED_UI, ())
+    }));
+  }
+  fn on_stop(&mut self, data: StateData<GameData>) {
+    let world = data.world;
+
+    world.write_resource::<GameSpeed>().paused = false;
+    if let Some(root) = self.root.take() {
+      delete_ui(world, root);
+    }
+  }
+  fn handle_event(&mut self, data: StateData<GameData>, event: StateEvent) -> SimpleTrans<'a, 'b> {
+    let world = data.world;
+    match &event {
+      StateEvent::Window(event) => {
+        if is_key_down(&event, VirtualKeyCode::Escape) || is_key_down(&event, VirtualKeyCode::P) {
+          if self.confirming {
+            self.set_confirming(world, false);
+          } else {
+            return Trans::Pop;
+          }
+        }
+      },
+      StateEvent::Ui(event) => {
+        if event.event_type == UiEventType::Click {
+          let target = Some(event.target);
+          if self.confirming {
+            if target == self.confirm_yes_button {
+              return Trans::Quit;
+            } else if target == self.confirm_no_button {
+              self.set_confirming(world, false);
+            }
+          } else if target == self.resume_button {
+            return Trans::Pop;
+          } else if target == self.restart_button {
+            //The level system picks this up once the game is running again
+            world.write_resource::<CommandChannel>().single_write(Command::RestartLevel);
+            return Trans::Pop;
+          } else if target == self.quit_button {
+            self.set_confirming(world, true);
+          }
+        }
+      },
+    }
+    Trans::None
+  }
+  fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans<'a, 'b> {
+    //The ui prefab loads asynchronously so keep looking until it's there
+    if self.confirm_panel.is_none() {
+      self.find_ui_components(data.world);
+    }
+    Trans::None
+  }
+}
+
+impl PausedState {
+  fn set_confirming(&mut self, world: &mut World, confirming: bool) {
+    if let Some(confirm_panel) = self.confirm_panel {
+      self.confirming = confirming;
+      set_ui_hidden(world, confirm_panel, !confirming);
+    }
+  }
+
+  fn find_ui_components(&mut self, world: &mut World) {
+    world.exec(|finder: UiFinder| {
+      self.resume_button = self.resume_button.or_else(|| finder.find("paused_resume"));
+      self.restart_button = self.restart_button.or_else(|| finder.find("paused_restart"));
+      self.quit_button = self.quit_button.or_else(|| finder.find("paused_quit"));
+      self.confirm_yes_button = self.confirm_yes_button.or_else(|| finder.find("paused_confirm_yes"));
+      self.confirm_no_button = self.confirm_no_button.or_else(|| finder.find("paused_confirm_no"));
+    });
+
+    //The confirm panel starts hidden, its buttons have to be found first so they get hidden with it
+    if self.confirm_yes_button.is_some() && self.confirm_no_button.is_some() {
+      self.confirm_panel = world.exec(|finder: UiFinder| finder.find("paused_confirm"));
+      self.set_confirming(world, false);
+    }
+  }
+}
@@ -1,8 +1,5 @@
 use amethyst::{
-  assets::{
-    Prefab,
-    Handle,
-  },
+  assets::Handle,
   core::Time,
   ecs::prelude::*,
   prelude::*,
@@ -27,9 +24,25 @@ use ::{
   resources::{
     SpawnStats,
     Level,
+    LevelOutcome,
+    LoadState,
+    Replay,
+    replay_path,
+    Progress,
+    GameSpeed,
+    Ability,
+    AbilityBudget,
   },
 };
 
+use super::{
+  ui::delete_ui,
+  LevelCompleteState,
+  LevelFailedState,
+  MenuState,
+  PausedState,
+};
+
 const UI_UPDATE_FRAMES: u64 = 20; //How many frames to wait between ui updates
 
 pub type RunningPrefabData = BasicScenePrefab<Vec<PosNormTex>>;
@@ -37,7 +50,7 @@ pub type RunningPrefabData = BasicScenePrefab<Vec<PosNormTex>>;
 ///Main state of the game. Main work done here is updating the gui.
 pub struct RunningState {
   running_ui_handle: Handle<UiPrefab>,
-  running_prefab_handle: Handle<Prefab<RunningPrefabData>>,
+  running_ui: Option<Entity>,
   fps_display: Option<Entity>,
 
   spawned_display: Option<Entity>,
@@ -46,6 +59,17 @@ pub struct RunningState {
 
   name_display: Option<Entity>,
   description_display: Option<Entity>,
+  speed_display: Option<Entity>,
+
+  lift_count_display: Option<Entity>,
+  change_direction_count_display: Option<Entity>,
+  ram_count_display: Option<Entity>,
+  blocker_count_display: Option<Entity>,
+  bridge_count_display: Option<Entity>,
+  dig_count_display: Option<Entity>,
+  floater_count_display: Option<Entity>,
+  bomb_count_display: Option<Entity>,
+  cube_count_display: Option<Entity>,
 }
 
 impl<'a, 'b> SimpleState<'a, 'b> for RunningState {
@@ -53,14 +77,29 @@ impl<'a, 'b> SimpleState<'a, 'b> for RunningState {
     info!("RunningState.on_start");
     let world = data.world;
 
-    self.initialise_prefab(world);
     self.initialise_ui(world);
   }
+  fn on_stop(&mut self, data: StateData<GameData>) {
+    if let Some(root) = self.running_ui.take() {
+      delete_ui(data.world, root);
+    }
+
+    data.world.read_resource::<Progress>().save();
+
+    //There's nothing recorded while a replay is being played back
+    if let Some(replay) = data.world.res.try_fetch::<Replay>() {
+      let path = replay_path();
+      info!("Saving replay to {}", path);
+      if let Err(e) = replay.write(&path) {
+        error!("Error saving replay: {}", e);
+      }
+    }
+  }
   fn handle_event(&mut self, _data: StateData<GameData>, event: StateEvent) -> SimpleTrans<'a, 'b> {
     match &event {
       StateEvent::Window(event) => {
-        if is_key_down(&event, VirtualKeyCode::Escape) {
-          return Trans::Quit;
+        if is_key_down(&event, VirtualKeyCode::Escape) || is_key_down(&event, VirtualKeyCode::P) {
+          return Trans::Push(Box::new(PausedState::default()));
         }
       },
       _ => {},
@@ -70,28 +109,49 @@ impl<'a, 'b> SimpleState<'a, 'b> for RunningState {
   fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans<'a, 'b> {
     let world = &mut data.world;
 
+    //Results screen's menu button was used
+    if world.read_resource::<Level>().load_state == LoadState::Idle {
+      return Trans::Switch(Box::new(MenuState::new(self.running_ui_handle.clone())));
+    }
+
     let frame_number = world.read_resource::<Time>().frame_number();
-    if frame_number < UI_UPDATE_FRAMES {
+    if !self.ui_found() {
       self.find_ui_components(world);
     } else if frame_number % UI_UPDATE_FRAMES == 0 {
       self.update_ui(world);
     }
 
-    Trans::None
+    //The level system sets this once every creep is saved or dead
+    let outcome = world.write_resource::<Level>().outcome.take();
+    match outcome {
+      Some(LevelOutcome::Complete) => Trans::Push(Box::new(LevelCompleteState::default())),
+      Some(LevelOutcome::Failed) => Trans::Push(Box::new(LevelFailedState::default())),
+      None => Trans::None,
+    }
   }
 }
 
 impl RunningState {
-  pub fn new(running_ui_handle: Handle<UiPrefab>, running_prefab_handle: Handle<Prefab<RunningPrefabData>>) -> Self {
+  pub fn new(running_ui_handle: Handle<UiPrefab>) -> Self {
     Self {
       running_ui_handle,
-      running_prefab_handle,
+      running_ui: None,
       fps_display: None,
       spawned_display: None,
       needed_percent_display: None,
       saved_percent_display: None,
       name_display: None,
       description_display: None,
+      speed_display: None,
+      lift_count_display: None,
+      change_direction_count_display: None,
+      ram_count_display: None,
+      blocker_count_display: None,
+      bridge_count_display: None,
+      dig_count_display: None,
+      floater_count_display: None,
+      bomb_count_display: None,
+      cube_count_display: None,
     }
   }
 
@@ -113,7 +173,7 @@ impl RunningState {
 
         if let Some(name_display) = ui_text.get_mut(name_display) {
           if name_display.text.is_empty() {
-            if let Some(ref name) = level.levels[level.current_level].name {
+            if let Some(name) = level.levels.get(level.current_level).and_then(|l| l.name.as_ref()) {
               name_display.text.push_str(name);
             }
           }
@@ -121,7 +181,7 @@ impl RunningState {
 
         if let Some(description_display) = ui_text.get_mut(description_display) {
           if description_display.text.is_empty() {
-            if let Some(ref description) = level.levels[level.current_level].description {
+            if let Some(description) = level.levels.get(level.current_level).and_then(|l| l.description.as_ref()) {
               description_display.text.push_str(description);
             }
           }
@@ -180,10 +240,65 @@ impl RunningState {
     }
   }
 
+  fn update_speed(&self, world: &mut World) {
+    let mut ui_text = world.write_storage::<UiText>();
+    if let Some(speed_display) = self.speed_display.and_then(|entity| ui_text.get_mut(entity)) {
+      let scale = world.read_resource::<GameSpeed>().scale;
+      speed_display.text = format!("SPEED: {}x", scale);
+    }
+  }
+
+  fn update_abilities(&self, world: &mut World) {
+    let mut ui_text = world.write_storage::<UiText>();
+    let ability_budget = world.read_resource::<AbilityBudget>();
+    let displays = [
+      (self.lift_count_display, Ability::Lift),
+      (self.change_direction_count_display, Ability::DirectionChanger),
+      (self.ram_count_display, Ability::Ram),
+      (self.blocker_count_display, Ability::Blocker),
+      (self.bridge_count_display, Ability::Bridge),
+      (self.dig_count_display, Ability::Dig),
+      (self.floater_count_display, Ability::Floater),
+      (self.bomb_count_display, Ability::Bomb),
+      (self.cube_count_display, Ability::Cube),
+    ];
+
+    for (display, ability) in displays.iter() {
+      if let Some(display) = display.and_then(|entity| ui_text.get_mut(entity)) {
+        //Unlimited abilities don't show a count
+        display.text = match ability_budget.remaining(*ability) {
+          Some(remaining) => format!("{}", remaining),
+          None => String::new(),
+        };
+      }
+    }
+  }
+
   fn update_ui(&mut self, world: &mut World) {
     self.update_name_and_description(world);
     self.update_fps(world);
     self.update_spawn_stats(world);
+    self.update_speed(world);
+    self.update_abilities(world);
+  }
+
+  fn ui_found(&self) -> bool {
+    self.fps_display.is_some()
+      && self.spawned_display.is_some()
+      && self.needed_percent_display.is_some()
+      && self.saved_percent_display.is_some()
+      && self.name_display.is_some()
+      && self.description_display.is_some()
+      && self.speed_display.is_some()
+      && self.lift_count_display.is_some()
+      && self.change_direction_count_display.is_some()
+      && self.ram_count_display.is_some()
+      && self.blocker_count_display.is_some()
+      && self.bridge_count_display.is_some()
+      && self.dig_count_display.is_some()
+      && self.floater_count_display.is_some()
+      && self.bomb_count_display.is_some()
+      && self.cube_count_display.is_some()
   }
 
   fn find_ui_components(&mut self, world: &mut World) {
@@ -235,19 +350,92 @@ impl RunningState {
         }
       });
     }
-  }
 
-  fn initialise_prefab(&self, world: &mut World) {
-    world
-      .create_entity()
-      .with(self.running_prefab_handle.clone())
-      .build();
+    if self.speed_display.is_none() {
+      world.exec(|finder: UiFinder| {
+        if let Some(entity) = finder.find("speed") {
+          self.speed_display = Some(entity);
+        }
+      });
+    }
+
+    if self.lift_count_display.is_none() {
+      world.exec(|finder: UiFinder| {
+        if let Some(entity) = finder.find("lift_count") {
+          self.lift_count_display = Some(entity);
+        }
+      });
+    }
+
+    if self.change_direction_count_display.is_none() {
+      world.exec(|finder: UiFinder| {
+        if let Some(entity) = finder.find("change_direction_count") {
+          self.change_direction_count_display = Some(entity);
+        }
+      });
+    }
+
+    if self.ram_count_display.is_none() {
+      world.exec(|finder: UiFinder| {
+        if let Some(entity) = finder.find("ram_count") {
+          self.ram_count_display = Some(entity);
+        }
+      });
+    }
+
+    if self.blocker_count_display.is_none() {
+      world.exec(|finder: UiFinder| {
+        if let Some(entity) = finder.find("blocker_count") {
+          self.blocker_count_display = Some(entity);
+        }
+      });
+    }
+
+    if self.bridge_count_display.is_none() {
+      world.exec(|finder: UiFinder| {
+        if let Some(entity) = finder.find("bridge_count") {
+          self.bridge_count_display = Some(entity);
+        }
+      });
+    }
+
+    if self.dig_count_display.is_none() {
+      world.exec(|finder: UiFinder| {
+        if let Some(entity) = finder.find("dig_count") {
+          self.dig_count_display = Some(entity);
+        }
+      });
+    }
+
+    if self.floater_count_display.is_none() {
+      world.exec(|finder: UiFinder| {
+        if let Some(entity) = finder.find("floater_count") {
+          self.floater_count_display = Some(entity);
+        }
+      });
+    }
+
+    if self.bomb_count_display.is_none() {
+      world.exec(|finder: UiFinder| {
+        if let Some(entity) = finder.find("bomb_count") {
+          self.bomb_count_display = Some(entity);
+        }
+      });
+    }
+
+    if self.cube_count_display.is_none() {
+      world.exec(|finder: UiFinder| {
+        if let Some(entity) = finder.find("cube_count") {
+          self.cube_count_display = Some(entity);
+        }
+      });
+    }
   }
 
-  fn initialise_ui(&self, world: &mut World) {
-    world
+  fn initialise_ui(&mut self, world: &mut World) {
+    self.running_ui = Some(world
       .create_entity()
       .with(self.running_ui_handle.clone())
-      .build();
+      .build());
   }
 }
\ No newline at end of file
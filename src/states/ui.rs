@@ -0,0 +1,41 @@
+use amethyst::{
+  core::transform::ParentHierarchy,
+  ecs::prelude::*,
+  renderer::Hidden,
+};
+
+///Deletes a ui prefab root along with everything under it. Deleting the root doesn't take the children with it
+pub fn delete_ui(world: &mut World, root: Entity) {
+  let to_delete = with_children(world, root);
+  world
+    .delete_entities(&to_delete)
+    .expect("Failed to remove ui");
+}
+
+///Hides or shows a ui entity along with everything under it. Hidden only applies to the entity it's on so a
+///hidden button would still show its text otherwise
+pub fn set_ui_hidden(world: &mut World, root: Entity, hidden: bool) {
+  let targets = with_children(world, root);
+  let mut hidden_storage = world.write_storage::<Hidden>();
+  for e in targets {
+    if hidden {
+      hidden_storage
+        .insert(e, Hidden)
+        .expect("Failed to insert component");
+    } else {
+      hidden_storage.remove(e);
+    }
+  }
+}
+
+//The root and everything under it
+fn with_children(world: &World, root: Entity) -> Vec<Entity> {
+  let hierarchy = world.read_resource::<ParentHierarchy>();
+  let entities = world.entities();
+  let mut all: Vec<Entity> = (&entities, &hierarchy.all_children(root))
+    .join()
+    .map(|(e, _)| e)
+    .collect();
+  all.push(root);
+  all
+}
@@ -1,31 +1,31 @@
-use amethyst::{
-  core::timing::Time,
-  ecs::prelude::*,
-};
+use amethyst::ecs::prelude::*;
 
 use ::{
   components::{
     Age as AgeComponent,
     Family,
   },
-  resources::SpawnStats,
+  resources::{
+    SpawnStats,
+    PhysicsWorld,
+  },
 };
 
-///Adds this frames delta time to all Age components
+///Adds this frames delta time to all Age components (nothing while paused)
 #[derive(Default)]
 pub struct Age;
 
 impl<'s> System<'s> for Age {
   type SystemData = (
     Entities<'s>,
-    Read<'s, Time>,
+    Read<'s, PhysicsWorld>,
     WriteStorage<'s, AgeComponent>,
     ReadStorage<'s, Family>,
     Write<'s, SpawnStats>,
   );
 
-  fn run(&mut self, (entities, time, mut age, family, mut spawn_stats): Self::SystemData) {
-    let delta = time.delta_seconds();
+  fn run(&mut self, (entities, physics_world, mut age, family, mut spawn_stats): Self::SystemData) {
+    let delta = physics_world.get_frame_delta();
 
     for (e, a) in (&entities, &mut age).join() {
       if entities.is_alive(e) {
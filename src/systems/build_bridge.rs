@@ -0,0 +1,116 @@
+use amethyst::{
+  ecs::prelude::*,
+  shrev::ReaderId,
+  core::{
+    transform::Transform,
+    cgmath::Vector2,
+  },
+};
+
+use ::{
+  components::{
+    Matriarch,
+    Walker,
+    Age,
+    Direction,
+    Color,
+  },
+  config::PhysicsConfig,
+  resources::{
+    Command,
+    CommandChannel,
+    SpawnStats,
+    PhysicsWorld,
+    Ability,
+    AbilityBudget,
+  },
+};
+
+///Turns the matriarch into a bridge that slopes up and away from it in the direction it was walking.
+#[derive(Default)]
+pub struct BuildBridge {
+  command_reader: Option<ReaderId<Command>>,
+}
+
+impl<'s> System<'s> for BuildBridge {
+  type SystemData = (
+    Entities<'s>,
+    Read<'s, CommandChannel>,
+    ReadStorage<'s, Matriarch>,
+    ReadStorage<'s, Transform>,
+    ReadStorage<'s, Walker>,
+    ReadStorage<'s, Color>,
+    Write<'s, PhysicsWorld>,
+    Read<'s, PhysicsConfig>,
+    Read<'s, LazyUpdate>,
+    ReadStorage<'s, Age>,
+    Write<'s, SpawnStats>,
+    Write<'s, AbilityBudget>,
+  );
+
+  fn setup(&mut self, res: &mut Resources) {
+    Self::SystemData::setup(res);
+    self.command_reader = Some(res.fetch_mut::<CommandChannel>().register_reader());
+  }
+
+  fn run(&mut self, (entities, commands, matriarchs, transforms, walkers, colors, mut physics_world, physics_config, updater, ages, mut spawn_stats, mut ability_budget): Self::SystemData) {
+    let mut build_bridge = false;
+    for command in commands.read(self.command_reader.as_mut().unwrap()) {
+      match command {
+        Command::BuildBridge => build_bridge = true,
+        _ => {},
+      }
+    }
+
+    if build_bridge {
+      for (e, m, t, w, a) in (&entities, &matriarchs, &transforms, &walkers, &ages).join() {
+        if entities.is_alive(e) {
+          //This test is to discard commands that were likely intended for a matriarch that just died
+          if (a.seconds - m.age_when_promoted) < physics_config.matriarch_grace_period {
+            continue;
+          }
+          if !ability_budget.use_ability(Ability::Bridge) {
+            debug!("No bridges left");
+            break;
+          }
+          debug!("Building bridge from Matriarch {:?}", e);
+          spawn_stats.abilities_used += 1;
+
+          let dir = match w.direction {
+            Direction::Left => -1.0,
+            Direction::Right => 1.0,
+          };
+
+          //The planks are laid end to end along a line going up at bridge_angle from the matriarch's feet
+          let angle = physics_config.bridge_angle;
+          let rotation = angle * dir;
+          let start = Vector2::new(t.translation.x, t.translation.y + physics_config.bridge_y_offset);
+
+          for i in 0..physics_config.bridge_planks {
+            let distance = (i as f32 + 0.5) * physics_config.bridge_plank_width;
+            let position = Vector2::new(
+              start.x + distance * angle.cos() * dir,
+              start.y + distance * angle.sin(),
+            );
+
+            let plank = physics_world.create_ground_box_collider(
+              &position,
+              &Vector2::new(physics_config.bridge_plank_width, physics_config.bridge_plank_height),
+              rotation);
+
+            //The collider means unload_level cleans the planks up with everything else
+            let mut builder = updater
+              .create_entity(&entities)
+              .with(plank);
+
+            if let Some(color) = colors.get(e) {
+              builder = builder.with(*color);
+            }
+
+            builder.build();
+          }
+        }
+      }
+    }
+  }
+}
@@ -25,11 +25,48 @@ use super::MatriarchPromote;
 use super::LaunchArea;
 use super::ConstantVelocity;
 use super::DropRam;
+use super::DropBlocker;
+use super::BuildBridge;
+use super::Dig;
+use super::DropFloater;
+use super::FallDamage;
+use super::DropBomb;
+use super::Switches;
+use super::Teleport;
+use super::ForceZones;
 use super::Level;
 use super::Exodus;
+use super::ReplayRecorder;
+use super::ReplayPlayback;
+use super::GameSpeed;
+use super::LevelWatcher;
+
+use ::resources::Replay;
 
 ///Bundle containing all user systems for the game
-pub struct GameBundle;
+#[derive(Default)]
+pub struct GameBundle {
+  //Leaves out the systems that only exist to put things on screen
+  headless: bool,
+  //Commands come from this instead of the player if it's set
+  replay: Option<Replay>,
+}
+
+impl GameBundle {
+  ///Bundle for running without a window (no meshes, indicators or fps logging)
+  pub fn headless() -> Self {
+    Self {
+      headless: true,
+      replay: None,
+    }
+  }
+
+  ///Plays back the replay instead of listening to player input
+  pub fn with_replay(mut self, replay: Replay) -> Self {
+    self.replay = Some(replay);
+    self
+  }
+}
 
 impl<'a, 'b> SystemBundle<'a, 'b> for GameBundle {
     fn build(self, builder: &mut DispatcherBuilder<'a, 'b>) -> Result<()> {
@@ -37,32 +74,61 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GameBundle {
 
       builder.add(Walker::default(), "walker_system", &[]);
       builder.add(ConstantVelocity::default(), "constant_velocity_system", &[]);
-      builder.add(LogFps::default(), "log_fps_system", &[]);
       builder.add(BasicVelocity::default(), "basic_velocity_system", &[]);
       builder.add(CameraMovement::default(), "camera_movement_system", &[]);
-      builder.add(PhysicsVisualizer::default(), "physics_visualizer_system", &[]);
-      builder.add(ShapeVisualizer::default(), "shape_visualizer_system", &[]);
-      builder.add(PlayerInput::default(), "player_input_system", &[]);
-      builder.add(Indicator::default(), "indicator_system", &[]);
 
-      builder.add(Age::default(), "age_system", &[]);
+      //Input runs after the physics step so the step count a command is recorded against is the same
+      //one it gets played back on. The playback system takes the player input name so everything that
+      //depends on input still runs after it.
+      match self.replay {
+        Some(replay) => builder.add(ReplayPlayback::new(replay), "player_input_system", &["physics_step_system"]),
+        None => {
+          builder.add(PlayerInput::default(), "player_input_system", &["physics_step_system"]);
+          //Not recorded during playback, it would overwrite the replay with a recording of itself
+          builder.add(ReplayRecorder::default(), "replay_recorder_system", &["player_input_system"]);
+        },
+      }
+
+      //These need the renderer resources (MaterialDefaults etc.) which don't exist headless
+      if !self.headless {
+        builder.add(LogFps::default(), "log_fps_system", &[]);
+        builder.add(PhysicsVisualizer::default(), "physics_visualizer_system", &[]);
+        builder.add(ShapeVisualizer::default(), "shape_visualizer_system", &[]);
+        builder.add(Indicator::default(), "indicator_system", &[]);
+        //Nobody is editing levels in a headless run
+        builder.add(LevelWatcher::default(), "level_watcher_system", &[]);
+      }
+
+      //Game clocks count the physics steps taken this frame
+      builder.add(Age::default(), "age_system", &["physics_step_system"]);
 
       //Murdering needs to happen last to make sure other commands are executed on the
       //matriarch before it's destroyed
       builder.add(DropCube::default(), "drop_cube_system", &["player_input_system"]);
       builder.add(DropLift::default(), "drop_lift_system", &["player_input_system"]);
       builder.add(DropRam::default(), "drop_ram_system", &["player_input_system"]);
+      builder.add(DropBlocker::default(), "drop_blocker_system", &["player_input_system"]);
+      builder.add(BuildBridge::default(), "build_bridge_system", &["player_input_system"]);
+      builder.add(Dig::default(), "dig_system", &["player_input_system"]);
+      builder.add(DropFloater::default(), "drop_floater_system", &["player_input_system"]);
+      builder.add(DropBomb::default(), "drop_bomb_system", &["player_input_system"]);
       builder.add(DropDirectionChanger::default(), "drop_direction_changer_system", &["player_input_system"]);
-      builder.add(Spawner::default(), "spawner_system", &[]);
+      builder.add(Spawner::default(), "spawner_system", &["physics_step_system"]);
       builder.add(Murder::default(), "murder_system", &[
         "player_input_system",
         "drop_cube_system",
         "drop_lift_system",
         "drop_direction_changer_system",
         "drop_ram_system",
+        "drop_blocker_system",
+        "build_bridge_system",
+        "dig_system",
+        "drop_floater_system",
+        "drop_bomb_system",
       ]);
       builder.add(Level::default(), "level_system", &["player_input_system"]);
       builder.add(Exodus::default(), "exodus_system", &["player_input_system"]);
+      builder.add(GameSpeed::default(), "game_speed_system", &["player_input_system"]);
 
       //These depend on the player input to reduce the chance of the player trying to do something and the matriarch
       //dying a fraction before they do.
@@ -72,18 +138,43 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GameBundle {
         "drop_cube_system",
         "drop_lift_system",
         "drop_direction_changer_system",
+        "drop_blocker_system",
+        "build_bridge_system",
+        "dig_system",
+        "drop_floater_system",
+        "drop_bomb_system",
       ]);
       builder.add(DeadlyArea::default(), "deadly_area_system", &[
         "physics_step_system",
         "drop_cube_system",
         "drop_lift_system",
         "drop_direction_changer_system",
+        "drop_blocker_system",
+        "build_bridge_system",
+        "dig_system",
+        "drop_floater_system",
+        "drop_bomb_system",
+      ]);
+      //Walkers that were saved or killed this frame have to be counted before fall damage gets a look at them
+      builder.add(FallDamage::default(), "fall_damage_system", &[
+        "physics_step_system",
+        "drop_floater_system",
+        "exit_system",
+        "deadly_area_system",
       ]);
+      builder.add(Switches::default(), "switches_system", &["physics_step_system"]);
+      builder.add(Teleport::default(), "teleport_system", &["physics_step_system"]);
+      builder.add(ForceZones::default(), "force_zones_system", &["physics_step_system"]);
       builder.add(LaunchArea::default(), "launch_area_system", &[
         "physics_step_system",
         "drop_cube_system",
         "drop_lift_system",
         "drop_direction_changer_system",
+        "drop_blocker_system",
+        "build_bridge_system",
+        "dig_system",
+        "drop_floater_system",
+        "drop_bomb_system",
       ]);
 
       //This could depend on age but since they all age together it really doesn't matter
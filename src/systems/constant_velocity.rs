@@ -27,7 +27,7 @@ impl<'s> System<'s> for ConstantVelocity {
     ReadStorage<'s, ConstantVelocityComponent>,
     ReadStorage<'s, Collider>,
     Write<'s, PhysicsWorld>,
-    ReadExpect<'s, Sounds>,
+    Option<Read<'s, Sounds>>,
     Read<'s, AssetStorage<Source>>,
     Option<Read<'s, Output>>,
   );
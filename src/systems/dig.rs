@@ -0,0 +1,195 @@
+use amethyst::{
+  ecs::prelude::*,
+  shrev::ReaderId,
+  core::{
+    transform::Transform,
+    cgmath::Vector2,
+  },
+};
+
+use ::{
+  components::{
+    Matriarch,
+    Walker,
+    Age,
+    Collider,
+    Color,
+    Diggable,
+  },
+  config::PhysicsConfig,
+  resources::{
+    Command,
+    CommandChannel,
+    SpawnStats,
+    PhysicsWorld,
+    Ability,
+    AbilityBudget,
+  },
+};
+
+//Anything left over from a dig that's thinner than this is removed as well
+const MIN_PIECE_SIZE: f32 = 1.0;
+
+///Digs through the diggable wall the matriarch is touching. The wall is replaced by the pieces either side of the hole.
+#[derive(Default)]
+pub struct Dig {
+  command_reader: Option<ReaderId<Command>>,
+}
+
+impl<'s> System<'s> for Dig {
+  type SystemData = (
+    Entities<'s>,
+    Read<'s, CommandChannel>,
+    ReadStorage<'s, Matriarch>,
+    ReadStorage<'s, Transform>,
+    ReadStorage<'s, Walker>,
+    ReadStorage<'s, Collider>,
+    ReadStorage<'s, Color>,
+    ReadStorage<'s, Diggable>,
+    Write<'s, PhysicsWorld>,
+    Read<'s, PhysicsConfig>,
+    Read<'s, LazyUpdate>,
+    ReadStorage<'s, Age>,
+    Write<'s, SpawnStats>,
+    Write<'s, AbilityBudget>,
+  );
+
+  fn setup(&mut self, res: &mut Resources) {
+    Self::SystemData::setup(res);
+    self.command_reader = Some(res.fetch_mut::<CommandChannel>().register_reader());
+  }
+
+  fn run(&mut self, (entities, commands, matriarchs, transforms, walkers, colliders, colors, diggables, mut physics_world, physics_config, updater, ages, mut spawn_stats, mut ability_budget): Self::SystemData) {
+    let mut dig = false;
+    for command in commands.read(self.command_reader.as_mut().unwrap()) {
+      match command {
+        Command::Dig => dig = true,
+        _ => {},
+      }
+    }
+
+    if dig {
+      for (e, m, t, c, _, a) in (&entities, &matriarchs, &transforms, &colliders, &walkers, &ages).join() {
+        if entities.is_alive(e) {
+          //This test is to discard commands that were likely intended for a matriarch that just died
+          if (a.seconds - m.age_when_promoted) < physics_config.matriarch_grace_period {
+            continue;
+          }
+
+          let x = t.translation.x;
+          let y = t.translation.y;
+
+          //Walls in front are preferred over the floor since they're the ones in the way
+          let mut wall = None;
+          if let Some(contacts) = physics_world.get_contacts(&c.collider_handle) {
+            for contact in contacts {
+              if let Some(entity) = physics_world.get_entity_for_collider(contact) {
+                if let Some(diggable) = diggables.get(entity) {
+                  let beside = (x - diggable.position.x).abs() > diggable.size.x * 0.5;
+                  if wall.is_none() || beside {
+                    wall = Some((entity, diggable, beside));
+                  }
+                  if beside {
+                    break;
+                  }
+                }
+              }
+            }
+          }
+
+          let (wall_entity, diggable, beside) = match wall {
+            Some(wall) => wall,
+            None => {
+              //Nothing is used up and the matriarch carries on
+              debug!("Matriarch {:?} isn't touching anything diggable", e);
+              break;
+            },
+          };
+
+          if !ability_budget.use_ability(Ability::Dig) {
+            debug!("No digs left");
+            break;
+          }
+          debug!("Matriarch {:?} digging through {:?}", e, wall_entity);
+          spawn_stats.abilities_used += 1;
+
+          //Rotated walls aren't split, they're just removed
+          let pieces = if diggable.rotation != 0.0 {
+            Vec::new()
+          } else if beside {
+            //A horizontal tunnel at the matriarch's height
+            split(diggable, y, physics_config.dig_size, false)
+          } else {
+            //A vertical hole where the matriarch is standing
+            split(diggable, x, physics_config.dig_size, true)
+          };
+
+          let color = colors.get(wall_entity).cloned();
+          for piece in pieces {
+            let collider = physics_world.create_ground_box_collider_with_material(
+              &piece.position,
+              &piece.size,
+              0.0,
+              &piece.material);
+
+            let mut builder = updater
+              .create_entity(&entities)
+              .with(collider)
+              .with(piece);
+
+            if let Some(color) = color {
+              builder = builder.with(color);
+            }
+
+            builder.build();
+          }
+
+          //The physics step system removes the collider when the entity goes
+          entities
+            .delete(wall_entity)
+            .expect("Failed to delete entity");
+
+          //Other abilities get the matriarch killed by the player input sending KillMatriarch but that would
+          //also happen when there's nothing to dig, so the matriarch is only used up once the dig has happened
+          spawn_stats.killed += 1;
+          entities
+            .delete(e)
+            .expect("Failed to delete entity");
+        }
+      }
+    }
+  }
+}
+
+//Cuts a hole of hole_size centered on `at` (an x position if vertical otherwise a y position) and returns what's left either side
+fn split(wall: &Diggable, at: f32, hole_size: f32, vertical: bool) -> Vec<Diggable> {
+  let (center, size) = if vertical {
+    (wall.position.x, wall.size.x)
+  } else {
+    (wall.position.y, wall.size.y)
+  };
+
+  let start = center - size * 0.5;
+  let end = center + size * 0.5;
+  let hole_start = at - hole_size * 0.5;
+  let hole_end = at + hole_size * 0.5;
+
+  let mut pieces = Vec::new();
+  for (from, to) in vec![(start, hole_start.min(end)), (hole_end.max(start), end)] {
+    if to - from < MIN_PIECE_SIZE {
+      continue;
+    }
+    let (position, size) = if vertical {
+      (Vector2::new((from + to) * 0.5, wall.position.y), Vector2::new(to - from, wall.size.y))
+    } else {
+      (Vector2::new(wall.position.x, (from + to) * 0.5), Vector2::new(wall.size.x, to - from))
+    };
+    pieces.push(Diggable {
+      position,
+      size,
+      rotation: 0.0,
+      material: wall.material,
+    });
+  }
+  pieces
+}
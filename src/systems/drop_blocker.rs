@@ -0,0 +1,158 @@
+use amethyst::{
+  ecs::prelude::*,
+  shrev::ReaderId,
+  core::{
+    transform::Transform,
+    cgmath::Vector2,
+  },
+};
+
+use ::{
+  components::{
+    Matriarch,
+    Walker,
+    Collider,
+    Blocker,
+    Age,
+    Direction,
+    Color,
+    Shape,
+  },
+  config::PhysicsConfig,
+  resources::{
+    Command,
+    CommandChannel,
+    SpawnStats,
+    PhysicsWorld,
+    Ability,
+    AbilityBudget,
+  },
+};
+
+///Turns the matriarch into a blocker that stands still and sends walkers back the way they came.
+///Like the ram, the blocker is a new entity made to look like the matriarch. Also does the proximity checking between blockers and walkers.
+#[derive(Default)]
+pub struct DropBlocker {
+  command_reader: Option<ReaderId<Command>>,
+}
+
+impl<'s> System<'s> for DropBlocker {
+  type SystemData = (
+    Entities<'s>,
+    Read<'s, CommandChannel>,
+    ReadStorage<'s, Matriarch>,
+    ReadStorage<'s, Transform>,
+    WriteStorage<'s, Walker>,
+    ReadStorage<'s, Blocker>,
+    ReadStorage<'s, Collider>,
+    ReadStorage<'s, Color>,
+    ReadStorage<'s, Shape>,
+    Write<'s, PhysicsWorld>,
+    Read<'s, PhysicsConfig>,
+    Read<'s, LazyUpdate>,
+    ReadStorage<'s, Age>,
+    Write<'s, SpawnStats>,
+    Write<'s, AbilityBudget>,
+  );
+
+  fn setup(&mut self, res: &mut Resources) {
+    Self::SystemData::setup(res);
+    self.command_reader = Some(res.fetch_mut::<CommandChannel>().register_reader());
+  }
+
+  fn run(&mut self, (entities, commands, matriarchs, transforms, mut walkers, blockers, colliders, colors, shapes, mut physics_world, physics_config, updater, ages, mut spawn_stats, mut ability_budget): Self::SystemData) {
+    let mut drop_blocker = false;
+    for command in commands.read(self.command_reader.as_mut().unwrap()) {
+      match command {
+        Command::DropBlocker => drop_blocker = true,
+        _ => {},
+      }
+    }
+
+    if drop_blocker {
+      for (e, m, t, _, a) in (&entities, &matriarchs, &transforms, &walkers, &ages).join() {
+        if entities.is_alive(e) {
+          //This test is to discard commands that were likely intended for a matriarch that just died
+          if (a.seconds - m.age_when_promoted) < physics_config.matriarch_grace_period {
+            continue;
+          }
+          if !ability_budget.use_ability(Ability::Blocker) {
+            debug!("No blockers left");
+            break;
+          }
+          debug!("Dropping blocker on Matriarch {:?}", e);
+          spawn_stats.abilities_used += 1;
+
+          let position = Vector2::new(t.translation.x, t.translation.y);
+
+          //The immovable part that stops anything that isn't turned around in time
+          let body = physics_world.create_ground_box_collider(
+            &position,
+            &Vector2::new(physics_config.blocker_width, physics_config.blocker_height),
+            0.0);
+
+          let mut builder = updater
+            .create_entity(&entities)
+            .with(body);
+
+          if let Some(color) = colors.get(e) {
+            builder = builder.with(*color);
+          }
+
+          if let Some(shape) = shapes.get(e) {
+            builder = builder.with(shape.clone());
+          }
+
+          builder.build();
+
+          //The sensor is wider than the body so walkers get turned before they bump into it
+          let sensor = physics_world.create_ground_box_sensor(
+            &position,
+            &Vector2::new(physics_config.blocker_sensor_width, physics_config.blocker_height),
+            0.0);
+
+          updater
+            .create_entity(&entities)
+            .with(sensor)
+            .with(Blocker {
+              x: t.translation.x,
+            })
+            .build();
+        }
+      }
+    }
+
+    //Same as the direction changer except the direction depends on which side of the blocker the walker is on
+    let mut changed = Vec::new();
+    for (blocker, sensor) in (&blockers, &colliders).join() {
+      if let Some(proxs) = physics_world.get_proximity(&sensor.collider_handle) {
+        for prox in proxs {
+          if let Some(entity) = physics_world.get_entity_for_collider(prox) {
+            if let (Some(walker), Some(transform)) = (walkers.get_mut(entity), transforms.get(entity)) {
+              let direction = if transform.translation.x < blocker.x {
+                Direction::Left
+              } else {
+                Direction::Right
+              };
+              if walker.direction != direction {
+                debug!("Blocker turning {:?} around", entity);
+                walker.direction = direction;
+                if let Some(body_handle) = physics_world.get_body_for_collider(prox) {
+                  changed.push(*body_handle);
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+    for c in changed {
+      if let Some(body) = physics_world.world.rigid_body_mut(c) {
+        let mut velocity = *body.velocity();
+        velocity.linear.x = 0.0;
+        velocity.angular = 0.0;
+        body.set_velocity(velocity);
+      }
+    }
+  }
+}
@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+
+use amethyst::{
+  ecs::prelude::*,
+  shrev::ReaderId,
+  assets::AssetStorage,
+  audio::{
+    Source,
+    output::Output,
+  },
+  core::{
+    transform::Transform,
+    cgmath::{
+      Vector2,
+      InnerSpace,
+    },
+  },
+};
+
+use nalgebra::Vector2 as naVector2;
+
+use ::{
+  components::{
+    Matriarch,
+    Walker,
+    Family,
+    Collider,
+    Bomb,
+    Destructible,
+    Age,
+    Color,
+  },
+  config::PhysicsConfig,
+  resources::{
+    Command,
+    CommandChannel,
+    SpawnStats,
+    PhysicsWorld,
+    Ability,
+    AbilityBudget,
+    Sounds,
+  },
+};
+
+///Drops a bomb on the matriarch. Also sets off bombs once their fuse has burnt down, pushing everything nearby away,
+///destroying destructible blocks and killing any creeps close to the middle.
+#[derive(Default)]
+pub struct DropBomb {
+  command_reader: Option<ReaderId<Command>>,
+}
+
+impl<'s> System<'s> for DropBomb {
+  type SystemData = (
+    Entities<'s>,
+    Read<'s, CommandChannel>,
+    ReadStorage<'s, Matriarch>,
+    ReadStorage<'s, Transform>,
+    WriteStorage<'s, Walker>,
+    ReadStorage<'s, Family>,
+    ReadStorage<'s, Collider>,
+    ReadStorage<'s, Bomb>,
+    ReadStorage<'s, Destructible>,
+    Write<'s, PhysicsWorld>,
+    Read<'s, PhysicsConfig>,
+    Read<'s, LazyUpdate>,
+    ReadStorage<'s, Age>,
+    Write<'s, SpawnStats>,
+    Write<'s, AbilityBudget>,
+    Option<Read<'s, Sounds>>,
+    Read<'s, AssetStorage<Source>>,
+    Option<Read<'s, Output>>,
+  );
+
+  fn setup(&mut self, res: &mut Resources) {
+    Self::SystemData::setup(res);
+    self.command_reader = Some(res.fetch_mut::<CommandChannel>().register_reader());
+  }
+
+  fn run(&mut self, (entities, commands, matriarchs, transforms, mut walkers, families, colliders, bombs, destructibles, mut physics_world, physics_config, updater, ages, mut spawn_stats, mut ability_budget, sounds, source_storage, output): Self::SystemData) {
+    let mut drop_bomb = false;
+    for command in commands.read(self.command_reader.as_mut().unwrap()) {
+      match command {
+        Command::DropBomb => drop_bomb = true,
+        _ => {},
+      }
+    }
+
+    if drop_bomb {
+      for (e, m, t, _, a) in (&entities, &matriarchs, &transforms, &walkers, &ages).join() {
+        if entities.is_alive(e) {
+          //This test is to discard commands that were likely intended for a matriarch that just died
+          if (a.seconds - m.age_when_promoted) < physics_config.matriarch_grace_period {
+            continue;
+          }
+          if !ability_budget.use_ability(Ability::Bomb) {
+            debug!("No bombs left");
+            break;
+          }
+          debug!("Dropping bomb on Matriarch {:?}", e);
+          spawn_stats.abilities_used += 1;
+
+          let collider = physics_world.create_rigid_body_with_box_collider(
+            &Vector2::new(t.translation.x, t.translation.y),
+            &Vector2::new(physics_config.bomb_size, physics_config.bomb_size),
+            0.0);
+
+          updater
+            .create_entity(&entities)
+            .with(collider)
+            .with(Age::default())
+            .with(Bomb {
+              fuse: physics_config.bomb_fuse,
+            })
+            .with(Color::new(0.1, 0.1, 0.1, 1.0))
+            .build();
+        }
+      }
+    }
+
+    //Bombs that are going off this frame
+    let mut explosions = Vec::new();
+    for (e, bomb, a, t) in (&entities, &bombs, &ages, &transforms).join() {
+      if a.seconds >= bomb.fuse {
+        explosions.push(Vector2::new(t.translation.x, t.translation.y));
+        entities
+          .delete(e)
+          .expect("Failed to delete entity");
+      }
+    }
+
+    //Deleted entities stay in the joins until the end of the frame so anything caught by more than one explosion
+    //would otherwise be counted again
+    let mut destroyed = HashSet::new();
+    for center in explosions {
+      debug!("Bomb exploded at {:?}", center);
+      for (e, c, t) in (&entities, &colliders, &transforms).join() {
+        //Ground colliders (walls, sensors) don't move
+        if c.body_handle.is_ground() || bombs.contains(e) || destroyed.contains(&e) {
+          continue;
+        }
+
+        let offset = Vector2::new(t.translation.x, t.translation.y) - center;
+        let distance = offset.magnitude();
+        if distance > physics_config.bomb_radius {
+          continue;
+        }
+
+        if destructibles.contains(e) {
+          debug!("Bomb destroyed {:?}", e);
+          destroyed.insert(e);
+          entities
+            .delete(e)
+            .expect("Failed to delete entity");
+          continue;
+        }
+
+        if families.contains(e) && distance < physics_config.bomb_core_radius {
+          debug!("Bomb killed {:?}", e);
+          destroyed.insert(e);
+          spawn_stats.killed += 1;
+          //Stops the exit and deadly area systems counting it as well
+          walkers.remove(e);
+          entities
+            .delete(e)
+            .expect("Failed to delete entity");
+
+          if let (Some(sounds), Some(output)) = (&sounds, &output) {
+            sounds.play_death(&source_storage, output);
+          }
+          continue;
+        }
+
+        //Straight up if it's right on top of the bomb, otherwise away from it getting weaker towards the edge
+        let direction = if distance > 0.0 {
+          offset / distance
+        } else {
+          Vector2::new(0.0, 1.0)
+        };
+        let strength = physics_config.bomb_velocity * (1.0 - distance / physics_config.bomb_radius);
+
+        physics_world.world.activate_body(c.body_handle);
+        if let Some(body) = physics_world.world.rigid_body_mut(c.body_handle) {
+          let mut velocity = *body.velocity();
+          velocity.linear += naVector2::new(direction.x, direction.y) * strength;
+          body.set_velocity(velocity);
+        }
+      }
+    }
+  }
+}
@@ -8,18 +8,25 @@ use amethyst::{
 };
 
 use ::{
+  config::PhysicsConfig,
   components::{
     Matriarch,
+    Walker,
+    Age,
   },
   resources::{
     Command,
     CommandChannel,
+    SpawnStats,
     PhysicsWorld,
+    Ability,
+    AbilityBudget,
+    Sprites,
   },
 };
 
 
-///Drops a cube on the matriarch. Current no player input triggers this.
+///Drops a cube on the matriarch. Size, density, lifetime and looks come from the physics config.
 #[derive(Default)]
 pub struct DropCube {
   command_reader: Option<ReaderId<Command>>,
@@ -31,8 +38,14 @@ impl<'s> System<'s> for DropCube {
     Read<'s, CommandChannel>,
     ReadStorage<'s, Matriarch>,
     ReadStorage<'s, Transform>,
+    ReadStorage<'s, Walker>,
     Write<'s, PhysicsWorld>,
+    Read<'s, PhysicsConfig>,
+    Option<Read<'s, Sprites>>,
     Read<'s, LazyUpdate>,
+    ReadStorage<'s, Age>,
+    Write<'s, SpawnStats>,
+    Write<'s, AbilityBudget>,
   );
 
   fn setup(&mut self, res: &mut Resources) {
@@ -40,7 +53,7 @@ impl<'s> System<'s> for DropCube {
     self.command_reader = Some(res.fetch_mut::<CommandChannel>().register_reader());
   }
 
-  fn run(&mut self, (entities, commands, matriarchs, transforms, mut physics_world, updater): Self::SystemData) {
+  fn run(&mut self, (entities, commands, matriarchs, transforms, walkers, mut physics_world, physics_config, sprites, updater, ages, mut spawn_stats, mut ability_budget): Self::SystemData) {
     let mut drop_cube = false;
     for command in commands.read(self.command_reader.as_mut().unwrap()) {
       match command {
@@ -50,21 +63,46 @@ impl<'s> System<'s> for DropCube {
     }
 
     if drop_cube {
-      for (e, _, t) in (&entities, &matriarchs, &transforms).join() {
+      for (e, m, t, _, a) in (&entities, &matriarchs, &transforms, &walkers, &ages).join() {
         if entities.is_alive(e) {
+          //This test is to discard commands that were likely intended for a matriarch that just died
+          if (a.seconds - m.age_when_promoted) < physics_config.matriarch_grace_period {
+            continue;
+          }
+          if !ability_budget.use_ability(Ability::Cube) {
+            debug!("No cubes left");
+            break;
+          }
           debug!("Dropping cube on Matriarch {:?}", e);
+          spawn_stats.abilities_used += 1;
 
-          let collider = physics_world.create_rigid_body_with_box_collider(
+          let collider = physics_world.create_rigid_body_with_box_collider_with_density(
             &Vector2::new(t.translation.x, t.translation.y),
-            &Vector2::new(40.0, 40.0),
-            0.0);
+            &Vector2::new(physics_config.cube_size, physics_config.cube_size),
+            0.0,
+            physics_config.cube_density);
 
-          updater
+          let age = Age {
+            seconds: 0.0,
+            max: physics_config.cube_life,
+          };
+
+          let mut builder = updater
             .create_entity(&entities)
             .with(collider)
-            .build();
+            .with(age);
+
+          //A color means it gets a colored mesh from the shape visualizer instead of the sprite
+          //Sprites aren't loaded when running headless
+          if let Some(color) = physics_config.cube_color {
+            builder = builder.with(color);
+          } else if let Some(sprites) = &sprites {
+            builder = builder.with(sprites.cube.clone());
+          }
+
+          builder.build();
         }
       }
     }
   }
-}
\ No newline at end of file
+}
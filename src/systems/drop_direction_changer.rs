@@ -21,7 +21,10 @@ use ::{
   resources::{
     Command,
     CommandChannel,
+    SpawnStats,
     PhysicsWorld,
+    Ability,
+    AbilityBudget,
     Sprites,
   },
 };
@@ -44,9 +47,11 @@ impl<'s> System<'s> for DropDirectionChanger {
     ReadStorage<'s, Collider>,
     Write<'s, PhysicsWorld>,
     Read<'s, PhysicsConfig>,
-    ReadExpect<'s, Sprites>,
+    Option<Read<'s, Sprites>>,
     Read<'s, LazyUpdate>,
     ReadStorage<'s, Age>,
+    Write<'s, SpawnStats>,
+    Write<'s, AbilityBudget>,
   );
 
   fn setup(&mut self, res: &mut Resources) {
@@ -54,7 +59,7 @@ impl<'s> System<'s> for DropDirectionChanger {
     self.command_reader = Some(res.fetch_mut::<CommandChannel>().register_reader());
   }
 
-  fn run(&mut self, (entities, commands, matriarchs, transforms, mut walkers, change_direction_components, colliders, mut physics_world, physics_config, sprites, updater, ages): Self::SystemData) {
+  fn run(&mut self, (entities, commands, matriarchs, transforms, mut walkers, change_direction_components, colliders, mut physics_world, physics_config, sprites, updater, ages, mut spawn_stats, mut ability_budget): Self::SystemData) {
     let mut drop_direction_changer = false;
     for command in commands.read(self.command_reader.as_mut().unwrap()) {
       match command {
@@ -70,12 +75,15 @@ impl<'s> System<'s> for DropDirectionChanger {
           if (a.seconds - m.age_when_promoted) < physics_config.matriarch_grace_period {
             continue;
           }
+          if !ability_budget.use_ability(Ability::DirectionChanger) {
+            debug!("No direction changers left");
+            break;
+          }
           debug!("Dropping direction changer on Matriarch {:?}", e);
+          spawn_stats.abilities_used += 1;
 
           let direction = w.direction.reversed();
 
-          let sprite = sprites.change_direction.clone();
-
           let sensor = physics_world.create_ground_box_sensor(
             &Vector2::new(t.translation.x, t.translation.y), //Pos
             &Vector2::new(physics_config.change_direction_width * 0.5, physics_config.change_direction_height * 0.5), //Size
@@ -85,12 +93,17 @@ impl<'s> System<'s> for DropDirectionChanger {
             direction: direction,
           };
 
-          updater
+          let mut builder = updater
             .create_entity(&entities)
-            .with(sprite)
             .with(sensor)
-            .with(change_direction)
-            .build();
+            .with(change_direction);
+
+          //Sprites aren't loaded when running headless
+          if let Some(sprites) = &sprites {
+            builder = builder.with(sprites.change_direction.clone());
+          }
+
+          builder.build();
         }
       }
     }
@@ -0,0 +1,126 @@
+use amethyst::{
+  ecs::prelude::*,
+  shrev::ReaderId,
+  core::{
+    transform::Transform,
+    cgmath::Vector2,
+  },
+};
+
+use ::{
+  components::{
+    Matriarch,
+    Walker,
+    Collider,
+    Drag,
+    FloaterArea,
+    Age,
+    Color,
+  },
+  config::PhysicsConfig,
+  resources::{
+    Command,
+    CommandChannel,
+    SpawnStats,
+    PhysicsWorld,
+    Ability,
+    AbilityBudget,
+  },
+};
+
+///Drops a floater sensor on the matriarch. Walkers that pass through it get drag so they can't fall fast enough to die.
+///Also does the proximity checking between floater sensors and walkers.
+#[derive(Default)]
+pub struct DropFloater {
+  command_reader: Option<ReaderId<Command>>,
+}
+
+impl<'s> System<'s> for DropFloater {
+  type SystemData = (
+    Entities<'s>,
+    Read<'s, CommandChannel>,
+    ReadStorage<'s, Matriarch>,
+    ReadStorage<'s, Transform>,
+    ReadStorage<'s, Walker>,
+    ReadStorage<'s, FloaterArea>,
+    ReadStorage<'s, Collider>,
+    ReadStorage<'s, Color>,
+    WriteStorage<'s, Drag>,
+    Write<'s, PhysicsWorld>,
+    Read<'s, PhysicsConfig>,
+    Read<'s, LazyUpdate>,
+    ReadStorage<'s, Age>,
+    Write<'s, SpawnStats>,
+    Write<'s, AbilityBudget>,
+  );
+
+  fn setup(&mut self, res: &mut Resources) {
+    Self::SystemData::setup(res);
+    self.command_reader = Some(res.fetch_mut::<CommandChannel>().register_reader());
+  }
+
+  fn run(&mut self, (entities, commands, matriarchs, transforms, walkers, floater_areas, colliders, colors, mut drags, mut physics_world, physics_config, updater, ages, mut spawn_stats, mut ability_budget): Self::SystemData) {
+    let mut drop_floater = false;
+    for command in commands.read(self.command_reader.as_mut().unwrap()) {
+      match command {
+        Command::DropFloater => drop_floater = true,
+        _ => {},
+      }
+    }
+
+    if drop_floater {
+      for (e, m, t, _, a) in (&entities, &matriarchs, &transforms, &walkers, &ages).join() {
+        if entities.is_alive(e) {
+          //This test is to discard commands that were likely intended for a matriarch that just died
+          if (a.seconds - m.age_when_promoted) < physics_config.matriarch_grace_period {
+            continue;
+          }
+          if !ability_budget.use_ability(Ability::Floater) {
+            debug!("No floaters left");
+            break;
+          }
+          debug!("Dropping floater on Matriarch {:?}", e);
+          spawn_stats.abilities_used += 1;
+
+          let sensor = physics_world.create_ground_box_sensor(
+            &Vector2::new(t.translation.x, t.translation.y),
+            &Vector2::new(physics_config.floater_width, physics_config.floater_height),
+            0.0);
+
+          let mut builder = updater
+            .create_entity(&entities)
+            .with(sensor)
+            .with(FloaterArea);
+
+          if let Some(color) = colors.get(e) {
+            builder = builder.with(*color);
+          }
+
+          builder.build();
+        }
+      }
+    }
+
+    //Go through fetching all sensors and giving drag to any walkers in proximity that don't have it yet
+    let mut floating = Vec::new();
+    for (_, sensor) in (&floater_areas, &colliders).join() {
+      if let Some(proxs) = physics_world.get_proximity(&sensor.collider_handle) {
+        for prox in proxs {
+          if let Some(entity) = physics_world.get_entity_for_collider(prox) {
+            if walkers.contains(entity) && !drags.contains(entity) {
+              floating.push(entity);
+            }
+          }
+        }
+      }
+    }
+    for entity in floating {
+      debug!("Giving {:?} a floater", entity);
+      drags
+        .insert(entity, Drag {
+          max_fall_velocity: physics_config.floater_max_fall_velocity,
+        })
+        .expect("Failed to insert component");
+    }
+  }
+}
@@ -18,7 +18,10 @@ use ::{
   resources::{
     Command,
     CommandChannel,
+    SpawnStats,
     PhysicsWorld,
+    Ability,
+    AbilityBudget,
     Sprites,
   },
 };
@@ -38,9 +41,11 @@ impl<'s> System<'s> for DropLift {
     ReadStorage<'s, Walker>,
     Write<'s, PhysicsWorld>,
     Read<'s, PhysicsConfig>,
-    ReadExpect<'s, Sprites>,
+    Option<Read<'s, Sprites>>,
     Read<'s, LazyUpdate>,
     ReadStorage<'s, Age>,
+    Write<'s, SpawnStats>,
+    Write<'s, AbilityBudget>,
   );
 
   fn setup(&mut self, res: &mut Resources) {
@@ -48,7 +53,7 @@ impl<'s> System<'s> for DropLift {
     self.command_reader = Some(res.fetch_mut::<CommandChannel>().register_reader());
   }
 
-  fn run(&mut self, (entities, commands, matriarchs, transforms, walkers, mut physics_world, physics_config, sprites, updater, ages): Self::SystemData) {
+  fn run(&mut self, (entities, commands, matriarchs, transforms, walkers, mut physics_world, physics_config, sprites, updater, ages, mut spawn_stats, mut ability_budget): Self::SystemData) {
     let mut drop_lift = false;
     for command in commands.read(self.command_reader.as_mut().unwrap()) {
       match command {
@@ -65,23 +70,31 @@ impl<'s> System<'s> for DropLift {
             continue;
           }
 
+          if !ability_budget.use_ability(Ability::Lift) {
+            debug!("No lifts left");
+            break;
+          }
           debug!("Dropping lift on Matriarch {:?}", e);
+          spawn_stats.abilities_used += 1;
 
-          let la = LaunchArea::new(w.direction);
-
-          let sprite = sprites.lift.clone();
+          let la = LaunchArea::new(w.direction, None);
 
           let sensor = physics_world.create_ground_box_sensor(
             &Vector2::new(t.translation.x, t.translation.y), //Pos
             &Vector2::new(physics_config.lift_width * 0.5, physics_config.lift_height * 0.5), //Size
             0.0);
 
-          updater
+          let mut builder = updater
             .create_entity(&entities)
             .with(la)
-            .with(sprite)
-            .with(sensor)
-            .build();
+            .with(sensor);
+
+          //Sprites aren't loaded when running headless
+          if let Some(sprites) = &sprites {
+            builder = builder.with(sprites.lift.clone());
+          }
+
+          builder.build();
         }
       }
     }
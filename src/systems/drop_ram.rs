@@ -24,7 +24,10 @@ use ::{
   resources::{
     Command,
     CommandChannel,
+    SpawnStats,
     PhysicsWorld,
+    Ability,
+    AbilityBudget,
   },
 };
 
@@ -48,6 +51,8 @@ impl<'s> System<'s> for DropRam {
     Read<'s, PhysicsConfig>,
     Read<'s, LazyUpdate>,
     ReadStorage<'s, Age>,
+    Write<'s, SpawnStats>,
+    Write<'s, AbilityBudget>,
   );
 
   fn setup(&mut self, res: &mut Resources) {
@@ -55,7 +60,7 @@ impl<'s> System<'s> for DropRam {
     self.command_reader = Some(res.fetch_mut::<CommandChannel>().register_reader());
   }
 
-  fn run(&mut self, (entities, commands, matriarchs, colors, shapes, walkers, mut physics_world, transforms, physics_config, updater, ages): Self::SystemData) {
+  fn run(&mut self, (entities, commands, matriarchs, colors, shapes, walkers, mut physics_world, transforms, physics_config, updater, ages, mut spawn_stats, mut ability_budget): Self::SystemData) {
     let mut drop_ram = false;
     for command in commands.read(self.command_reader.as_mut().unwrap()) {
       match command {
@@ -71,7 +76,12 @@ impl<'s> System<'s> for DropRam {
           if (a.seconds - m.age_when_promoted) < physics_config.matriarch_grace_period {
             continue;
           }
+          if !ability_budget.use_ability(Ability::Ram) {
+            debug!("No rams left");
+            break;
+          }
           debug!("Dropping ram on Matriarch {:?}", e);
+          spawn_stats.abilities_used += 1;
 
           let collider = physics_world.create_rigid_body_with_box_collider_with_density(
             &Vector2::new(t.translation.x, t.translation.y),
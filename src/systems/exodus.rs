@@ -29,7 +29,7 @@ impl<'s> System<'s> for Exodus {
   type SystemData = (
     Read<'s, CommandChannel>,
     WriteStorage<'s, Spawner>,
-    ReadExpect<'s, Sounds>,
+    Option<Read<'s, Sounds>>,
     Read<'s, AssetStorage<Source>>,
     Option<Read<'s, Output>>,
   );
@@ -49,7 +49,7 @@ impl<'s> System<'s> for Exodus {
     }
 
     if exodus {
-      if let Some(output) = &output {
+      if let (Some(sounds), Some(output)) = (&sounds, &output) {
         sounds.play_exodus(&source_storage, output);
       }
       for s in (&mut spawners).join() {
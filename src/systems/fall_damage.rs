@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use amethyst::{
+  ecs::prelude::*,
+  assets::AssetStorage,
+  audio::{
+    Source,
+    output::Output,
+  },
+};
+
+use ::{
+  config::PhysicsConfig,
+  components::{
+    Walker,
+    Collider,
+    Drag,
+  },
+  resources::{
+    PhysicsWorld,
+    SpawnStats,
+    Sounds,
+    Level,
+  },
+};
+
+//Falling slower than this (meters per second) while touching something counts as having landed
+const LANDED_VELOCITY: f32 = 0.5;
+
+///Keeps track of how fast walkers are falling and kills any that land too hard. Walkers with drag are safe.
+///Runs after the exit and deadly area systems so walkers they've already counted are skipped.
+#[derive(Default)]
+pub struct FallDamage {
+  //Fastest downward velocity of each walker since it last landed
+  peak_fall_velocity: HashMap<Entity, f32>,
+}
+
+impl<'s> System<'s> for FallDamage {
+  type SystemData = (
+    Entities<'s>,
+    WriteStorage<'s, Walker>,
+    ReadStorage<'s, Collider>,
+    ReadStorage<'s, Drag>,
+    Read<'s, PhysicsWorld>,
+    Read<'s, PhysicsConfig>,
+    Read<'s, Level>,
+    Write<'s, SpawnStats>,
+    Option<Read<'s, Sounds>>,
+    Read<'s, AssetStorage<Source>>,
+    Option<Read<'s, Output>>,
+  );
+
+  fn run(&mut self, (entities, mut walkers, colliders, drags, physics_world, physics_config, level, mut spawn_stats, sounds, source_storage, output): Self::SystemData) {
+    //Forget about walkers that have gone
+    self.peak_fall_velocity.retain(|e, _| entities.is_alive(*e));
+
+    let fall_damage_velocity = match level
+      .levels
+      .get(level.current_level)
+      .and_then(|l| l.fall_damage_velocity)
+      .or(physics_config.fall_damage_velocity)
+    {
+      Some(fall_damage_velocity) => fall_damage_velocity,
+      None => return,
+    };
+
+    let mut landed_too_hard = Vec::new();
+    for (e, _, collider, _) in (&entities, &walkers, &colliders, !&drags).join() {
+      let fall_velocity = match physics_world.world.rigid_body(collider.body_handle) {
+        Some(body) => -body.velocity().linear.y,
+        None => continue,
+      };
+
+      //The physics step records the fastest it fell during each step so the impact itself isn't missed
+      if collider.peak_fall_velocity > LANDED_VELOCITY {
+        let peak = self.peak_fall_velocity.entry(e).or_insert(0.0);
+        *peak = peak.max(collider.peak_fall_velocity);
+      }
+
+      //Being slow at the top of a jump isn't landing, it has to be touching something
+      let touching = physics_world.get_contacts(&collider.collider_handle).map_or(false, |c| c.len() > 0);
+      if fall_velocity <= LANDED_VELOCITY && touching {
+        if let Some(peak) = self.peak_fall_velocity.remove(&e) {
+          if peak > fall_damage_velocity {
+            debug!("{:?} landed at {} m/s and died", e, peak);
+            landed_too_hard.push(e);
+          }
+        }
+      }
+    }
+
+    for e in landed_too_hard {
+      if let Some(_) = walkers.remove(e) {
+        spawn_stats.killed += 1;
+        entities
+          .delete(e)
+          .expect("Failed to delete entity");
+
+        if let (Some(sounds), Some(output)) = (&sounds, &output) {
+          sounds.play_death(&source_storage, output);
+        }
+      }
+    }
+  }
+}
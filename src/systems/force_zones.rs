@@ -0,0 +1,72 @@
+use amethyst::ecs::prelude::*;
+
+use nphysics2d::force_generator::{
+  ForceGeneratorHandle,
+  ConstantAcceleration,
+};
+use nalgebra::{
+  Vector2,
+  zero,
+};
+
+use ::{
+  components::{
+    Walker,
+    Collider,
+    ForceZone,
+    ForceFilter,
+  },
+  resources::PhysicsWorld,
+};
+
+///Pushes the bodies inside force zones with a force generator per zone
+#[derive(Default)]
+pub struct ForceZones {
+  forces: Vec<ForceGeneratorHandle>,
+}
+
+impl<'s> System<'s> for ForceZones {
+  type SystemData = (
+    ReadStorage<'s, Walker>,
+    ReadStorage<'s, ForceZone>,
+    ReadStorage<'s, Collider>,
+    Write<'s, PhysicsWorld>,
+  );
+
+  fn run(&mut self, (walkers, force_zones, colliders, mut physics_world): Self::SystemData) {
+    //Same as the walker system, the generators are rebuilt every frame as bodies come and go
+    for force in self.forces.drain(..) {
+      physics_world.world.remove_force_generator(force);
+    }
+
+    for (zone, sensor) in (&force_zones, &colliders).join() {
+      let mut bodies = Vec::new();
+      if let Some(proxs) = physics_world.get_proximity(&sensor.collider_handle) {
+        for prox in proxs {
+          if zone.filter == ForceFilter::Walkers {
+            match physics_world.get_entity_for_collider(prox) {
+              Some(entity) if walkers.contains(entity) => {},
+              _ => continue,
+            }
+          }
+          if let Some(body_handle) = physics_world.get_body_for_collider(prox) {
+            //Walls and other sensors can't be moved
+            if !body_handle.is_ground() && !bodies.contains(body_handle) {
+              bodies.push(*body_handle);
+            }
+          }
+        }
+      }
+
+      if bodies.len() > 0 {
+        let mut force = ConstantAcceleration::new(Vector2::new(zone.force.x, zone.force.y), zero());
+        for body in bodies {
+          //Sleeping bodies ignore forces
+          physics_world.world.activate_body(body);
+          force.add_body_part(body);
+        }
+        self.forces.push(physics_world.world.add_force_generator(force));
+      }
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+use amethyst::{
+  ecs::prelude::*,
+  shrev::ReaderId,
+};
+
+use ::resources::{
+  Command,
+  CommandChannel,
+  GameSpeed as GameSpeedResource,
+};
+
+///Doubles or halves the game speed when the speed commands are sent.
+#[derive(Default)]
+pub struct GameSpeed {
+  command_reader: Option<ReaderId<Command>>,
+}
+
+impl<'s> System<'s> for GameSpeed {
+  type SystemData = (
+    Read<'s, CommandChannel>,
+    Write<'s, GameSpeedResource>,
+  );
+
+  fn setup(&mut self, res: &mut Resources) {
+    Self::SystemData::setup(res);
+    self.command_reader = Some(res.fetch_mut::<CommandChannel>().register_reader());
+  }
+
+  fn run(&mut self, (commands, mut game_speed): Self::SystemData) {
+    for command in commands.read(self.command_reader.as_mut().unwrap()) {
+      match command {
+        Command::SpeedUp => game_speed.speed_up(),
+        Command::SlowDown => game_speed.slow_down(),
+        _ => {},
+      }
+    }
+  }
+}
@@ -17,6 +17,7 @@ use ::{
     Indicator as IndicatorComponent,
     Matriarch,
     Shape as ShapeComponent,
+    ShapeMesh,
     Color,
   },
 };
@@ -73,7 +74,7 @@ impl<'s> System<'s> for Indicator {
       transform.rotation = Quaternion::from(Euler { x: Deg(0.0), y: Deg(0.0), z: Deg(-90.0) })
                          * Quaternion::from(Euler { x: Deg(0.0), y: Deg(90.0), z: Deg(0.0) });
       let shape = ShapeComponent {
-        shape: Shape::Cone(10),
+        shape: ShapeMesh::Generated(Shape::Cone(10)),
         scale: (4.0, 4.0, 4.0),
       };
       let color = Color::new(0.8, 0.2, 0.2, 1.0);
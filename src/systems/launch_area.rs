@@ -2,6 +2,7 @@ use std::cmp::PartialEq;
 
 use amethyst::{
   ecs::prelude::*,
+  core::cgmath::Vector2,
   assets::AssetStorage,
   audio::{
     Source,
@@ -38,6 +39,7 @@ use ::{
 #[derive(Debug)]
 struct ToLaunch {
   direction: Direction,
+  velocity: Option<Vector2<f32>>,
   handle: BodyHandle,
 }
 
@@ -58,7 +60,7 @@ impl<'s> System<'s> for LaunchArea {
     ReadStorage<'s, Collider>,
     Write<'s, PhysicsWorld>,
     Read<'s, PhysicsConfig>,
-    ReadExpect<'s, Sounds>,
+    Option<Read<'s, Sounds>>,
     Read<'s, AssetStorage<Source>>,
     Option<Read<'s, Output>>,
   );
@@ -84,6 +86,7 @@ impl<'s> System<'s> for LaunchArea {
               if let Some(body_handle) = physics_world.get_body_for_collider(prox) {
                 let tl = ToLaunch {
                   direction: launch_area.direction,
+                  velocity: launch_area.velocity,
                   handle: *body_handle,
                 };
                 if !to_launch.contains(&tl) {
@@ -98,33 +101,23 @@ impl<'s> System<'s> for LaunchArea {
 
     //No point playing the same sound multiple times in the same frame
     if to_launch.len() > 0 {
-      if let Some(output) = &output {
+      if let (Some(sounds), Some(output)) = (&sounds, &output) {
         sounds.play_lift(&source_storage, output);
       }
 
-      let velocity_left = Velocity::new(
-        naVector2::new(
-          physics_config.lift_velocity.x * -1.0,
-          physics_config.lift_velocity.y,
-        ),
-        physics_config.lift_velocity_rotation * -1.0,
-      );
-
-      let velocity_right = Velocity::new(
-        naVector2::new(
-          physics_config.lift_velocity.x,
-          physics_config.lift_velocity.y,
-        ),
-        physics_config.lift_velocity_rotation,
-      );
-
       //TODO: Would be interesting to benchmark while(pop) vs ref iter + derefing the handle
-      while let Some(ToLaunch {direction, handle}) = to_launch.pop() {
+      while let Some(ToLaunch {direction, velocity, handle}) = to_launch.pop() {
         if let Some(body) = physics_world.world.rigid_body_mut(handle) {
-          match direction {
-            Direction::Left => body.set_velocity(velocity_left),
-            Direction::Right => body.set_velocity(velocity_right),
-          }
+          let velocity = velocity.unwrap_or(physics_config.lift_velocity);
+          //Launch velocities are for going right
+          let sign = match direction {
+            Direction::Left => -1.0,
+            Direction::Right => 1.0,
+          };
+          body.set_velocity(Velocity::new(
+            naVector2::new(velocity.x * sign, velocity.y),
+            physics_config.lift_velocity_rotation * sign,
+          ));
         }
       }
     }
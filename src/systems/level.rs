@@ -2,7 +2,6 @@ use amethyst::{
   core::{
     transform::components::Transform,
     cgmath::Vector2,
-    timing::Time,
   },
   ecs::prelude::*,
   controls::FlyControlTag,
@@ -15,15 +14,36 @@ use ::{
     LevelConfig,
     CameraOverrides,
     CameraConfig,
-    load_game_config,
+    MovingPlatform,
+    PlatformMode,
+    SwitchConfig,
+    DoorConfig,
+    TeleporterPair,
+    LiftConfig,
+    DirectionChangerConfig,
+    ForceZoneConfig,
+    PhysicsConfig,
+    MaterialConfig,
+    Cuboid,
+    CuboidSet,
+    ShapedObject,
+    ShapedSet,
+    load_level_config,
+    find_level_problems,
+    find_problems_in_level,
   },
   resources::{
     PhysicsWorld,
+    BodyMaterial,
     SpawnStats,
     Command,
     CommandChannel,
     Level as LevelResource,
     LoadState,
+    LevelOutcome,
+    Progress,
+    AbilityBudget,
+    Sprites,
   },
   components::{
     Color,
@@ -32,12 +52,23 @@ use ::{
     Exit,
     DeadlyArea,
     Collider,
+    Walker,
+    Diggable,
+    Destructible,
+    Platform,
+    Switch,
+    Door,
+    Teleporter,
+    LaunchArea,
+    ChangeDirection,
+    ForceZone,
   },
 };
 
 enum ObjectType {
   GroundCollider,
   RigidBodyCollider,
+  KinematicCollider,
   Sensor,
 }
 
@@ -49,11 +80,15 @@ pub struct Level {
 
 impl<'s> System<'s> for Level {
   type SystemData = (
+    Entities<'s>,
+    ReadStorage<'s, Walker>,
     Read<'s, CommandChannel>,
-    Read<'s, Time>,
+    Read<'s, PhysicsWorld>,
     Read<'s, LazyUpdate>,
     Write<'s, LevelResource>,
     Read<'s, SpawnStats>,
+    Write<'s, Progress>,
+    Read<'s, LevelsConfig>,
   );
 
   fn setup(&mut self, res: &mut Resources) {
@@ -61,11 +96,37 @@ impl<'s> System<'s> for Level {
     self.command_reader = Some(res.fetch_mut::<CommandChannel>().register_reader());
   }
 
-  fn run(&mut self, (commands, time, updater, mut level_resource, spawn_stats): Self::SystemData) {
-    level_resource.runtime += time.delta_seconds();
+  fn run(&mut self, (entities, walkers, commands, physics_world, updater, mut level_resource, spawn_stats, mut progress, levels_config): Self::SystemData) {
+    level_resource.runtime += physics_world.get_frame_delta();
+
+    //Always drain the commands so ones sent while loading or in the menu don't pile up
+    let mut reload = false;
+    let mut next = false;
+    let mut prev = false;
+    let mut restart = false;
+    let mut exit = false;
+    let mut start = None;
+    for command in commands.read(self.command_reader.as_mut().unwrap()) {
+      match command {
+        Command::ReloadLevels | Command::FileChanged => reload = true,
+        Command::RestartLevel => restart = true,
+        Command::NextLevel => next = true,
+        Command::PreviousLevel => prev = true,
+        Command::ExitLevel => exit = true,
+        Command::StartLevel(level) => start = Some(*level),
+        _ => {},
+      }
+    }
 
     let mut pending_action = true;
     match level_resource.load_state {
+      LoadState::Idle => {
+        if let Some(level) = start {
+          //load_config makes sure the level exists
+          level_resource.current_level = level;
+          level_resource.load_state = LoadState::NeedConfig;
+        }
+      },
       LoadState::NeedConfig => updater.exec_mut(move |world| load_config(world)),
       LoadState::NeedLoad => updater.exec_mut(move |world| load_level(world)),
       //The purpose of this state is purely to delay this system by 1 frame before load
@@ -77,24 +138,38 @@ impl<'s> System<'s> for Level {
     }
 
     if !pending_action {
-      let mut reload = false;
-      let mut next = false;
-      let mut prev = false;
-      let mut restart = false;
-      for command in commands.read(self.command_reader.as_mut().unwrap()) {
-        match command {
-          Command::ReloadLevels => reload = true,
-          Command::RestartLevel => restart = true,
-          Command::NextLevel => next = true,
-          Command::PreviousLevel => prev = true,
-          _ => {},
-        }
+      let current_level = level_resource.current_level;
+      progress.record_saved_ratio(current_level, spawn_stats.saved_ratio());
+
+      if !level_resource.cleared && spawn_stats.total > 0 && spawn_stats.saved_ratio() >= spawn_stats.win_ratio {
+        info!("Level {} cleared in {:.*}s", current_level, 1, level_resource.runtime);
+        level_resource.cleared = true;
+        progress.record_clear(current_level, level_resource.runtime, spawn_stats.abilities_used);
+        progress.save();
       }
 
-      if reload {
-        updater.exec_mut(move |world| reload_config(world));
+      //Walkers are created lazily so the saved + killed check stops the level ending between a spawn and the walker appearing
+      if !level_resource.ended
+        && spawn_stats.total > 0
+        && spawn_stats.spawned >= spawn_stats.total
+        && spawn_stats.saved + spawn_stats.killed >= spawn_stats.spawned
+        && (&entities, &walkers).join().next().is_none()
+      {
+        level_resource.ended = true;
+        level_resource.outcome = Some(if level_resource.cleared {
+          LevelOutcome::Complete
+        } else {
+          LevelOutcome::Failed
+        });
+      }
+
+      if exit {
+        updater.exec_mut(move |world| exit_level(world));
+      } else if reload {
+        updater.exec_mut(move |world| reload_level(world));
       } else if next {
-        if spawn_stats.saved_ratio() >= spawn_stats.win_ratio {
+        //Levels that have been cleared before can be skipped
+        if level_resource.cleared || progress.is_unlocked(current_level + 1, levels_config.start_level) {
           updater.exec_mut(move |world| next_level(world));
         }
       } else if prev {
@@ -115,22 +190,21 @@ fn load_config(world: &mut World) {
   info!("Loading levels config");
   let level_config = world.read_resource::<LevelsConfig>();
 
+  //Nothing to fall back to on the first load so just shout about it
+  for problem in find_level_problems(&level_config, &world.read_resource::<PhysicsConfig>(), &world.read_resource::<SpawnerConfig>()) {
+    error!("{}", problem);
+  }
+
   //Do some basic checks on the config
   if level_config.levels.len() == 0 {
     panic!("No levels defined in levels config");
   }
-  let start_level = level_config.start_level.unwrap_or(0);
-  if level_config.levels.len() <= start_level {
-    panic!("start_level > max level");
-  }
 
-  //Update the level resource
+  //Update the level resource. The level to start on is picked by whoever sets up the resource (the menu, a replay or headless)
   let mut level_resource = world.write_resource::<LevelResource>();
   level_resource.levels = level_config.levels.clone();
   //Make sure current level isn't higher than max level
   level_resource.current_level = level_resource.current_level.min(level_resource.levels.len() - 1);
-  //Jump to  the start level if it's higher
-  level_resource.current_level = level_resource.current_level.max(start_level);
 
   //This will trigger load on the next frame
   level_resource.load_state = LoadState::NeedLoad;
@@ -142,6 +216,17 @@ fn load_level(world: &mut World) {
     panic!("load_level called but load_state wasn't NeedLoad");
   }
 
+  //A level file that failed to load has already been reported by load_config. Go back to the menu rather than playing an empty level
+  let load_error = {
+    let level_resource = world.read_resource::<LevelResource>();
+    level_resource.levels[level_resource.current_level].load_error.clone()
+  };
+  if let Some(error) = load_error {
+    error!("Can't play level: {}", error);
+    world.write_resource::<LevelResource>().load_state = LoadState::Idle;
+    return;
+  }
+
   info!("Loading level");
 
   //Reset spawn stats
@@ -160,6 +245,11 @@ fn load_level(world: &mut World) {
     //Create the level contents
     create_level_objects(world, &level);
 
+    {
+      let mut ability_budget = world.write_resource::<AbilityBudget>();
+      *ability_budget = AbilityBudget::new(level.abilities.as_ref());
+    }
+
     //Update the camera if there are overrides
     if let Some(camera_overrides) = &level.camera_overrides {
       Some(update_camera(world, camera_overrides))
@@ -169,10 +259,16 @@ fn load_level(world: &mut World) {
   };
 
 
+  let load_step = world.read_resource::<PhysicsWorld>().get_step_count();
+
   let mut level_resource = world.write_resource::<LevelResource>();
   level_resource.prev_camera_settings = prev_cam;
   level_resource.load_state = LoadState::Loaded;
+  level_resource.load_step = load_step;
   level_resource.runtime = 0.0;
+  level_resource.cleared = false;
+  level_resource.ended = false;
+  level_resource.outcome = None;
 }
 
 //Cleans up anything we've created
@@ -183,6 +279,8 @@ fn unload_level(world: &mut World) {
 
   info!("Unloading level");
 
+  world.read_resource::<Progress>().save();
+
   let prev_cam = {
     let mut level_resource = world.write_resource::<LevelResource>();
     level_resource.prev_camera_settings.take()
@@ -209,6 +307,14 @@ fn unload_level(world: &mut World) {
   level_resource.load_state = LoadState::PhysicsCleanup;
 }
 
+//Nothing gets loaded after this so there's no need to wait for the physics cleanup
+fn exit_level(world: &mut World) {
+  unload_level(world);
+
+  let mut level_resource = world.write_resource::<LevelResource>();
+  level_resource.load_state = LoadState::Idle;
+}
+
 fn restart_level(world: &mut World) {
   unload_level(world);
 }
@@ -241,18 +347,35 @@ fn prev_level(world: &mut World) {
   }
 }
 
-//Attempts to load LevelsConfig from the filesystem and push it into the LevelsConfig resource is load succeeds
-fn reload_config(world: &mut World) {
+//Re-reads the current level's file (and grid) and restarts it with the changes. The other levels aren't touched
+//so a mistake in one of them doesn't stop the one being worked on from reloading
+fn reload_level(world: &mut World) {
   if LoadState::Loaded != world.read_resource::<LevelResource>().load_state {
-    panic!("reload_config called but load_state wasn't Loaded");
+    panic!("reload_level called but load_state wasn't Loaded");
   }
 
-  info!("Reloading level config");
-  match load_game_config() {
-    Ok(new_config) => {
+  let index = world.read_resource::<LevelResource>().current_level;
+  let file = match world.read_resource::<LevelsConfig>().level_files.get(index) {
+    Some(file) => file.clone(),
+    None => return,
+  };
+
+  info!("Reloading level {} from {}", index, file);
+  match load_level_config(&file) {
+    Ok(new_level) => {
+      //Keep playing the old version rather than loading a broken one
+      let problems = find_problems_in_level(index, &new_level, &world.read_resource::<PhysicsConfig>());
+      if problems.len() > 0 {
+        for problem in problems {
+          error!("{}", problem);
+        }
+        error!("Level has problems, not reloading");
+        return;
+      }
+
       {
         let mut config = world.write_resource::<LevelsConfig>();
-        *config = new_config.levels;
+        config.levels[index] = new_level;
       }
 
       //Clear up the current level
@@ -262,26 +385,38 @@ fn reload_config(world: &mut World) {
       let mut level_resource = world.write_resource::<LevelResource>();
       level_resource.load_state = LoadState::NeedConfig;
     },
-    Err(e) => error!("Error loading GameConfig: {}", e),
+    Err(e) => error!("Error loading level {}: {}", file, e),
   }
 }
 
 fn create_object(world: &mut World, width: f32, height: f32, x: f32, y: f32, otype: ObjectType, color: Option<Color>, rotation: Option<f32>, add_extras: Option<&Fn(EntityBuilder) -> EntityBuilder>) {
+  create_object_with_material(world, width, height, x, y, otype, color, rotation, &BodyMaterial::default(), add_extras);
+}
+
+//Sensors ignore the material
+fn create_object_with_material(world: &mut World, width: f32, height: f32, x: f32, y: f32, otype: ObjectType, color: Option<Color>, rotation: Option<f32>, material: &BodyMaterial, add_extras: Option<&Fn(EntityBuilder) -> EntityBuilder>) {
   let object = {
     let mut physics_world = world.write_resource::<PhysicsWorld>();
     match otype {
       ObjectType::GroundCollider =>
-        physics_world.create_ground_box_collider(
+        physics_world.create_ground_box_collider_with_material(
           &Vector2::new(x, y),
           &Vector2::new(width, height),
-          rotation.unwrap_or(0.0)),
+          rotation.unwrap_or(0.0),
+          material),
       ObjectType::Sensor =>
         physics_world.create_ground_box_sensor(
           &Vector2::new(x, y),
           &Vector2::new(width, height),
           rotation.unwrap_or(0.0)),
       ObjectType::RigidBodyCollider =>
-        physics_world.create_rigid_body_with_box_collider(
+        physics_world.create_rigid_body_with_box_collider_with_material(
+          &Vector2::new(x, y),
+          &Vector2::new(width, height),
+          rotation.unwrap_or(0.0),
+          material),
+      ObjectType::KinematicCollider =>
+        physics_world.create_kinematic_box_collider(
           &Vector2::new(x, y),
           &Vector2::new(width, height),
           rotation.unwrap_or(0.0)),
@@ -302,8 +437,48 @@ fn create_object(world: &mut World, width: f32, height: f32, x: f32, y: f32, oty
   builder.build();
 }
 
-fn create_wall(world: &mut World, width: f32, height: f32, x: f32, y: f32, color: Option<Color>, rotation: Option<f32>) {
-  create_object(
+//Looks up a material preset and applies the object's overrides to it
+fn find_material(world: &World, name: Option<&String>, overrides: MaterialConfig) -> BodyMaterial {
+  let mut material = BodyMaterial::default();
+  if let Some(name) = name {
+    match world.read_resource::<PhysicsConfig>().materials.get(name) {
+      Some(preset) => material = preset.apply(material),
+      None => warn!("No material called {}, using the default", name),
+    }
+  }
+
+  overrides.apply(material)
+}
+
+fn cuboid_material(world: &World, cuboid: &Cuboid, set: &CuboidSet) -> BodyMaterial {
+  find_material(world, cuboid.material.as_ref().or(set.material.as_ref()), MaterialConfig {
+    friction: cuboid.friction,
+    restitution: cuboid.restitution,
+    density: cuboid.density,
+  })
+}
+
+fn shaped_material(world: &World, object: &ShapedObject, set: &ShapedSet) -> BodyMaterial {
+  find_material(world, object.material.as_ref().or(set.material.as_ref()), MaterialConfig {
+    friction: object.friction,
+    restitution: object.restitution,
+    density: object.density,
+  })
+}
+
+fn create_wall(world: &mut World, width: f32, height: f32, x: f32, y: f32, color: Option<Color>, rotation: Option<f32>, diggable: bool, material: &BodyMaterial) {
+  let diggable = if diggable {
+    Some(Diggable {
+      position: Vector2::new(x, y),
+      size: Vector2::new(width, height),
+      rotation: rotation.unwrap_or(0.0),
+      material: *material,
+    })
+  } else {
+    None
+  };
+
+  create_object_with_material(
     world,
     width,
     height,
@@ -312,7 +487,11 @@ fn create_wall(world: &mut World, width: f32, height: f32, x: f32, y: f32, color
     ObjectType::GroundCollider,
     color,
     rotation,
-    None
+    material,
+    Some(&|builder| match diggable {
+      Some(ref diggable) => builder.with(diggable.clone()),
+      None => builder,
+    }),
   );
 }
 
@@ -344,8 +523,8 @@ fn create_exit(world: &mut World, width: f32, height: f32, x: f32, y: f32, color
   );
 }
 
-fn create_block(world: &mut World, width: f32, height: f32, x: f32, y: f32, color: Option<Color>, rotation: Option<f32>) {
-  create_object(
+fn create_block(world: &mut World, width: f32, height: f32, x: f32, y: f32, color: Option<Color>, rotation: Option<f32>, destructible: bool, material: &BodyMaterial) {
+  create_object_with_material(
     world,
     width,
     height,
@@ -354,7 +533,212 @@ fn create_block(world: &mut World, width: f32, height: f32, x: f32, y: f32, colo
     ObjectType::RigidBodyCollider,
     color,
     rotation,
+    material,
+    Some(&|builder| if destructible {
+      builder.with(Destructible)
+    } else {
+      builder
+    }),
+  );
+}
+
+//Only walls (GroundCollider) and blocks (RigidBodyCollider) can have shapes
+fn create_shaped_object(world: &mut World, object: &ShapedObject, otype: ObjectType, color: Option<Color>, destructible: bool, material: &BodyMaterial) {
+  let collider = {
+    let mut physics_world = world.write_resource::<PhysicsWorld>();
+    let position = Vector2::new(object.position.x, object.position.y);
+    let shape = object.shape.collider_shape();
+    let rotation = object.rotation.unwrap_or(0.0);
+    match otype {
+      ObjectType::RigidBodyCollider =>
+        physics_world.create_rigid_body_with_shape(&position, &shape, rotation, material),
+      _ =>
+        physics_world.create_ground_collider_with_shape(&position, &shape, rotation, material),
+    }
+  };
+
+  //The validator reports shapes that can't be made, they're skipped here
+  let collider = match collider {
+    Some(collider) => collider,
+    None => return,
+  };
+
+  let mut builder = world.create_entity();
+  builder = builder.with(collider);
+
+  if let Some(color) = color {
+    builder = builder.with(color);
+  }
+
+  if destructible {
+    builder = builder.with(Destructible);
+  }
+
+  builder.build();
+}
+
+fn create_platform(world: &mut World, platform: &MovingPlatform, color: Option<Color>) {
+  //The validator reports platforms without waypoints, they're skipped here
+  let start = match platform.waypoints.first() {
+    Some(start) => *start,
+    None => return,
+  };
+  let looping = platform.mode == Some(PlatformMode::Loop);
+  create_object(
+    world,
+    platform.size.x,
+    platform.size.y,
+    start.x,
+    start.y,
+    ObjectType::KinematicCollider,
+    color,
+    None,
+    Some(&|builder| builder.with(Platform::new(platform.waypoints.clone(), platform.speed, looping))),
+  );
+}
+
+fn create_switch(world: &mut World, switch: &SwitchConfig, color: Option<Color>) {
+  create_object(
+    world,
+    switch.size.x,
+    switch.size.y,
+    switch.position.x,
+    switch.position.y,
+    ObjectType::Sensor,
+    color,
     None,
+    Some(&|builder| builder.with(Switch {
+      targets: switch.targets.clone(),
+      latch: switch.latch.unwrap_or(false),
+      pressed: false,
+    })),
+  );
+}
+
+fn create_door(world: &mut World, door: &DoorConfig, color: Option<Color>) {
+  create_object(
+    world,
+    door.size.x,
+    door.size.y,
+    door.position.x,
+    door.position.y,
+    ObjectType::GroundCollider,
+    color,
+    door.rotation,
+    //Doors are created closed, the switches system opens the ones that start open on the first frame
+    Some(&|builder| builder.with(Door {
+      id: door.id.clone(),
+      open_by_default: door.open.unwrap_or(false),
+      open: false,
+    })),
+  );
+}
+
+fn create_teleporter_pair(world: &mut World, pair: &TeleporterPair, color: Option<Color>) {
+  let keep_velocity = pair.keep_velocity.unwrap_or(true);
+  //Each end sends walkers to the other
+  for (from, to) in &[(pair.a, pair.b), (pair.b, pair.a)] {
+    create_object(
+      world,
+      pair.size.x,
+      pair.size.y,
+      from.x,
+      from.y,
+      ObjectType::Sensor,
+      color,
+      None,
+      Some(&|builder| builder.with(Teleporter {
+        destination: Vector2::new(to.x, to.y),
+        keep_velocity,
+      })),
+    );
+  }
+}
+
+fn create_lift(world: &mut World, lift: &LiftConfig, color: Option<Color>) {
+  //Same size as the ones dropped by DropLift
+  let size = match lift.size {
+    Some(size) => Vector2::new(size.x, size.y),
+    None => {
+      let physics_config = world.read_resource::<PhysicsConfig>();
+      Vector2::new(physics_config.lift_width * 0.5, physics_config.lift_height * 0.5)
+    },
+  };
+  //Sprites aren't loaded when running headless
+  let sprite = match color {
+    Some(_) => None,
+    None => world.res.try_fetch::<Sprites>().map(|sprites| sprites.lift.clone()),
+  };
+
+  create_object(
+    world,
+    size.x,
+    size.y,
+    lift.position.x,
+    lift.position.y,
+    ObjectType::Sensor,
+    color,
+    None,
+    Some(&|builder| {
+      let builder = builder.with(LaunchArea::new(lift.direction, lift.velocity));
+      match sprite {
+        Some(ref sprite) => builder.with(sprite.clone()),
+        None => builder,
+      }
+    }),
+  );
+}
+
+fn create_direction_changer(world: &mut World, changer: &DirectionChangerConfig, color: Option<Color>) {
+  //Same size as the ones dropped by DropDirectionChanger
+  let size = match changer.size {
+    Some(size) => Vector2::new(size.x, size.y),
+    None => {
+      let physics_config = world.read_resource::<PhysicsConfig>();
+      Vector2::new(physics_config.change_direction_width * 0.5, physics_config.change_direction_height * 0.5)
+    },
+  };
+  //Sprites aren't loaded when running headless
+  let sprite = match color {
+    Some(_) => None,
+    None => world.res.try_fetch::<Sprites>().map(|sprites| sprites.change_direction.clone()),
+  };
+
+  create_object(
+    world,
+    size.x,
+    size.y,
+    changer.position.x,
+    changer.position.y,
+    ObjectType::Sensor,
+    color,
+    None,
+    Some(&|builder| {
+      let builder = builder.with(ChangeDirection {
+        direction: changer.direction,
+      });
+      match sprite {
+        Some(ref sprite) => builder.with(sprite.clone()),
+        None => builder,
+      }
+    }),
+  );
+}
+
+fn create_force_zone(world: &mut World, zone: &ForceZoneConfig, color: Option<Color>) {
+  create_object(
+    world,
+    zone.size.x,
+    zone.size.y,
+    zone.position.x,
+    zone.position.y,
+    ObjectType::Sensor,
+    color,
+    zone.rotation,
+    Some(&|builder| builder.with(ForceZone {
+      force: zone.force,
+      filter: zone.filter.unwrap_or_default(),
+    })),
   );
 }
 
@@ -385,6 +769,7 @@ fn create_spawner(world: &mut World, width: f32, height: f32, x: f32, y: f32, co
 fn create_level_objects(world: &mut World, level: &LevelConfig) {
   if let Some(ref set) = level.walls {
     for o in &set.list {
+      let material = cuboid_material(world, o, set);
       create_wall(
         world,
         o.size.x,
@@ -393,6 +778,8 @@ fn create_level_objects(world: &mut World, level: &LevelConfig) {
         o.position.y,
         o.color.or(set.color),
         o.rotation,
+        o.diggable.or(set.diggable).unwrap_or(false),
+        &material,
       );
     }
   }
@@ -457,6 +844,7 @@ fn create_level_objects(world: &mut World, level: &LevelConfig) {
 
   if let Some(ref set) = level.blocks {
     for o in &set.list {
+      let material = cuboid_material(world, o, set);
       create_block(
         world,
         o.size.x,
@@ -465,9 +853,68 @@ fn create_level_objects(world: &mut World, level: &LevelConfig) {
         o.position.y,
         o.color.or(set.color),
         o.rotation,
+        o.destructible.or(set.destructible).unwrap_or(false),
+        &material,
       );
     }
   }
+
+  if let Some(ref set) = level.shaped_walls {
+    for o in &set.list {
+      let material = shaped_material(world, o, set);
+      create_shaped_object(world, o, ObjectType::GroundCollider, o.color.or(set.color), false, &material);
+    }
+  }
+
+  if let Some(ref set) = level.shaped_blocks {
+    for o in &set.list {
+      let material = shaped_material(world, o, set);
+      let destructible = o.destructible.or(set.destructible).unwrap_or(false);
+      create_shaped_object(world, o, ObjectType::RigidBodyCollider, o.color.or(set.color), destructible, &material);
+    }
+  }
+
+  if let Some(ref set) = level.platforms {
+    for o in &set.list {
+      create_platform(world, o, o.color.or(set.color));
+    }
+  }
+
+  if let Some(ref set) = level.switches {
+    for o in &set.list {
+      create_switch(world, o, o.color.or(set.color));
+    }
+  }
+
+  if let Some(ref set) = level.doors {
+    for o in &set.list {
+      create_door(world, o, o.color.or(set.color));
+    }
+  }
+
+  if let Some(ref set) = level.teleporters {
+    for o in &set.list {
+      create_teleporter_pair(world, o, o.color.or(set.color));
+    }
+  }
+
+  if let Some(ref set) = level.lifts {
+    for o in &set.list {
+      create_lift(world, o, o.color.or(set.color));
+    }
+  }
+
+  if let Some(ref set) = level.direction_changers {
+    for o in &set.list {
+      create_direction_changer(world, o, o.color.or(set.color));
+    }
+  }
+
+  if let Some(ref set) = level.force_zones {
+    for o in &set.list {
+      create_force_zone(world, o, o.color.or(set.color));
+    }
+  }
 }
 
 fn update_camera(world: &mut World, overrides: &CameraOverrides) -> CameraOverrides {
@@ -0,0 +1,66 @@
+use std::{
+  fs,
+  time::SystemTime,
+};
+
+use amethyst::{
+  core::timing::Time,
+  ecs::prelude::*,
+};
+
+use ::{
+  config::LevelsConfig,
+  resources::{
+    Command,
+    CommandChannel,
+    Level,
+    LoadState,
+  },
+};
+
+//How often to check the files, in seconds
+const POLL_INTERVAL: f32 = 0.5;
+
+///Watches the files the current level is built from and reloads the level when one of them changes.
+#[derive(Default)]
+pub struct LevelWatcher {
+  elapsed: f32,
+  //Level index and the modified times of its files on the last check
+  last_check: Option<(usize, Vec<Option<SystemTime>>)>,
+}
+
+impl<'s> System<'s> for LevelWatcher {
+  type SystemData = (
+    Read<'s, Time>,
+    Read<'s, Level>,
+    Read<'s, LevelsConfig>,
+    Write<'s, CommandChannel>,
+  );
+
+  fn run(&mut self, (time, level, levels_config, mut commands): Self::SystemData) {
+    //Real time rather than game time so it still works while paused
+    self.elapsed += time.delta_seconds();
+    if self.elapsed < POLL_INTERVAL || level.load_state != LoadState::Loaded {
+      return;
+    }
+    self.elapsed = 0.0;
+
+    let current_level = level.current_level;
+    let modified: Vec<Option<SystemTime>> = levels_config
+      .level_paths(current_level)
+      .iter()
+      .map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+      .collect();
+
+    let changed = match self.last_check {
+      Some((index, ref last_modified)) => index == current_level && *last_modified != modified,
+      None => false,
+    };
+    if changed {
+      info!("Level {} changed on disk", current_level);
+      commands.single_write(Command::FileChanged);
+    }
+
+    self.last_check = Some((current_level, modified));
+  }
+}
@@ -8,7 +8,10 @@ use amethyst::{
   Error,
 };
 
-use ::resources::Sounds;
+use ::resources::{
+  Sounds,
+  Replay,
+};
 
 mod basic_velocity;
 mod bundle;
@@ -33,8 +36,21 @@ mod matriarch_promote;
 mod launch_area;
 mod constant_velocity;
 mod drop_ram;
+mod drop_blocker;
+mod build_bridge;
+mod dig;
+mod drop_floater;
+mod fall_damage;
+mod drop_bomb;
+mod switches;
+mod teleport;
+mod force_zones;
 mod level;
 mod exodus;
+mod replay_recorder;
+mod replay_playback;
+mod game_speed;
+mod level_watcher;
 
 pub use self::basic_velocity::*;
 pub use self::bundle::*;
@@ -59,18 +75,36 @@ pub use self::matriarch_promote::*;
 pub use self::launch_area::*;
 pub use self::constant_velocity::*;
 pub use self::drop_ram::*;
+pub use self::drop_blocker::*;
+pub use self::build_bridge::*;
+pub use self::dig::*;
+pub use self::drop_floater::*;
+pub use self::fall_damage::*;
+pub use self::drop_bomb::*;
+pub use self::switches::*;
+pub use self::teleport::*;
+pub use self::force_zones::*;
 pub use self::level::*;
 pub use self::exodus::*;
+pub use self::replay_recorder::*;
+pub use self::replay_playback::*;
+pub use self::game_speed::*;
+pub use self::level_watcher::*;
 
 //Not exactly sure how to structure this
 //Want the function in systems so things like TransformBundle dependencies on my systems
 //  aren't scattered about but it also probably doesn't make sense to configure everything
 //  inside systems...
 //Registers game systems and any core systems they depend on
-pub fn register_systems<'a, 'b>(builder: GameDataBuilder<'a, 'b>) -> Result<GameDataBuilder<'a, 'b>, Error> {
+pub fn register_systems<'a, 'b>(builder: GameDataBuilder<'a, 'b>, replay: Option<Replay>) -> Result<GameDataBuilder<'a, 'b>, Error> {
+  let game_bundle = match replay {
+    Some(replay) => GameBundle::default().with_replay(replay),
+    None => GameBundle::default(),
+  };
+
   builder
     .with(FPSCounterSystem, "fps_counter_system", &[])
-    .with_bundle(GameBundle)?
+    .with_bundle(game_bundle)?
     .with_bundle(TransformBundle::new())?
     .with_bundle(AudioBundle::new(|sounds: &mut Sounds| sounds.next_music()))
 }
\ No newline at end of file
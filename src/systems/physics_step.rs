@@ -4,16 +4,29 @@ use amethyst::{
   core::{
     //transform::components::Transform,
     timing::Time,
+    cgmath::Vector2,
   },
   ecs::prelude::*,
 };
 
+use nalgebra::Vector2 as naVector2;
+
 use ::{
-  components::Collider,
-  resources::PhysicsWorld,
+  components::{
+    Collider,
+    Platform,
+    Drag,
+  },
+  resources::{
+    PhysicsWorld,
+    GameSpeed,
+    SCALE_PIXELS_PER_METER,
+    SCALE_METERS_PER_PIXEL,
+  },
 };
 
-///System steps the physics world. Also manages deleting colliders when their components get removed.
+///System steps the physics world, moves platforms along their paths and caps the fall speed of anything with drag. Also manages deleting
+///colliders when their components get removed.
 #[derive(Default)]
 pub struct PhysicsStep {
   dirty: BitSet,
@@ -27,8 +40,11 @@ impl<'s> System<'s> for PhysicsStep {
   type SystemData = (
     Entities<'s>,
     Read<'s, Time>,
+    Read<'s, GameSpeed>,
     Write<'s, PhysicsWorld>,
     WriteStorage<'s, Collider>,
+    WriteStorage<'s, Platform>,
+    ReadStorage<'s, Drag>,
   );
 
   fn setup(&mut self, res: &mut Resources) {
@@ -38,11 +54,22 @@ impl<'s> System<'s> for PhysicsStep {
     self.removed_reader_id = Some(storage.track_removed());
   }
 
-  fn run(&mut self, (entities, time, mut physics_world, mut colliders): Self::SystemData) {
-    let delta = time.delta_seconds();
+  fn run(&mut self, (entities, time, game_speed, mut physics_world, mut colliders, mut platforms, drags): Self::SystemData) {
+    //No time gets added while paused so the world doesn't step
+    let delta = game_speed.delta_seconds(&time);
     physics_world.add_time(delta);
 
-    while physics_world.step() {
+    for c in (&mut colliders).join() {
+      c.peak_fall_velocity = 0.0;
+    }
+
+    while physics_world.step_ready() {
+      //Platforms are given a new velocity before every step so they don't overshoot their waypoints
+      drive_platforms(&mut physics_world, &colliders, &mut platforms);
+      physics_world.step();
+      //Capped after every step, otherwise at higher game speeds things fall faster than they should between frames
+      limit_fall_velocity(&mut physics_world, &colliders, &drags);
+
       //This is done here rather than the transform update system because c.update_transform must be called per physics step
       for c in (&mut colliders).join() {
         let collider = physics_world
@@ -51,6 +78,11 @@ impl<'s> System<'s> for PhysicsStep {
           .expect("Failed to resolve collider handle to collider");
 
         c.update_transform(&collider.position());
+
+        //Sampled every step so a hard landing isn't missed when several steps happen in one frame
+        if let Some(body) = physics_world.world.rigid_body(c.body_handle) {
+          c.peak_fall_velocity = c.peak_fall_velocity.max(-body.velocity().linear.y);
+        }
       }
     }
 
@@ -93,4 +125,32 @@ impl<'s> System<'s> for PhysicsStep {
     }
 
   }
+}
+
+fn drive_platforms(physics_world: &mut PhysicsWorld, colliders: &WriteStorage<Collider>, platforms: &mut WriteStorage<Platform>) {
+  let timestep = physics_world.get_timestep();
+  for (c, platform) in (colliders, platforms).join() {
+    physics_world.world.activate_body(c.body_handle);
+    if let Some(body) = physics_world.world.rigid_body_mut(c.body_handle) {
+      let position = body.position().translation.vector * SCALE_PIXELS_PER_METER;
+      let velocity = platform.velocity(Vector2::new(position.x, position.y), timestep) * SCALE_METERS_PER_PIXEL;
+
+      let mut body_velocity = *body.velocity();
+      body_velocity.linear = naVector2::new(velocity.x, velocity.y);
+      body_velocity.angular = 0.0;
+      body.set_velocity(body_velocity);
+    }
+  }
+}
+
+fn limit_fall_velocity(physics_world: &mut PhysicsWorld, colliders: &WriteStorage<Collider>, drags: &ReadStorage<Drag>) {
+  for (c, drag) in (colliders, drags).join() {
+    if let Some(body) = physics_world.world.rigid_body_mut(c.body_handle) {
+      let mut velocity = *body.velocity();
+      if velocity.linear.y < -drag.max_fall_velocity {
+        velocity.linear.y = -drag.max_fall_velocity;
+        body.set_velocity(velocity);
+      }
+    }
+  }
 }
\ No newline at end of file
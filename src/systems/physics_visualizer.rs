@@ -1,15 +1,22 @@
 
 use amethyst::{
+  core::cgmath::{
+    Vector2,
+    InnerSpace,
+  },
   ecs::prelude::*,
   renderer::Shape,
 };
 
+use nalgebra::Point2;
+
 use ncollide2d::shape as ncshape;
 
 use ::{
   components::{
     Collider,
     Shape as ShapeComponent,
+    ShapeMesh,
   },
   resources::{
     PhysicsWorld,
@@ -18,6 +25,8 @@ use ::{
 };
 
 const Z_SIZE: f32 = 0.15;
+//How thick polylines are drawn (in pixels)
+const POLYLINE_WIDTH: f32 = 2.0;
 
 ///Finds colliders without shapes and creates shapes for them
 #[derive(Default)]
@@ -49,9 +58,25 @@ impl<'s> System<'s> for PhysicsVisualizer {
           let w = (he.x + margin) * SCALE_PIXELS_PER_METER;
           let h = (he.y + margin) * SCALE_PIXELS_PER_METER;
           ShapeComponent {
-            shape: Shape::Cube,
+            shape: ShapeMesh::Generated(Shape::Cube),
             scale: (w, h, Z_SIZE),
           }
+        } else if let Some(s) = shape.as_shape::<ncshape::Ball<f32>>() {
+          let r = (s.radius() + margin) * SCALE_PIXELS_PER_METER;
+          ShapeComponent {
+            shape: ShapeMesh::Generated(Shape::Sphere(16, 16)),
+            scale: (r, r, Z_SIZE),
+          }
+        } else if let Some(s) = shape.as_shape::<ncshape::ConvexPolygon<f32>>() {
+          ShapeComponent {
+            shape: ShapeMesh::Triangles(polygon_triangles(&to_pixels(s.points()))),
+            scale: (1.0, 1.0, 1.0),
+          }
+        } else if let Some(s) = shape.as_shape::<ncshape::Polyline<f32>>() {
+          ShapeComponent {
+            shape: ShapeMesh::Triangles(polyline_triangles(&to_pixels(s.vertices()))),
+            scale: (1.0, 1.0, 1.0),
+          }
         } else {
           panic!("Unknown collider shape in PhysicsVisualizer");
         }
@@ -59,4 +84,39 @@ impl<'s> System<'s> for PhysicsVisualizer {
       updater.insert(entity, shape);
     }
   }
+}
+
+fn to_pixels(points: &[Point2<f32>]) -> Vec<Vector2<f32>> {
+  points
+    .iter()
+    .map(|p| Vector2::new(p.x, p.y) * SCALE_PIXELS_PER_METER)
+    .collect()
+}
+
+//Convex so the triangles can all fan out from the first point
+fn polygon_triangles(points: &[Vector2<f32>]) -> Vec<Vector2<f32>> {
+  let mut triangles = Vec::new();
+  for i in 1..points.len().saturating_sub(1) {
+    triangles.push(points[0]);
+    triangles.push(points[i]);
+    triangles.push(points[i + 1]);
+  }
+  triangles
+}
+
+//Each segment is a thin rectangle
+fn polyline_triangles(points: &[Vector2<f32>]) -> Vec<Vector2<f32>> {
+  let mut triangles = Vec::new();
+  for segment in points.windows(2) {
+    let (a, b) = (segment[0], segment[1]);
+    let along = b - a;
+    let length = along.magnitude();
+    if length == 0.0 {
+      continue;
+    }
+    let side = Vector2::new(-along.y, along.x) / length * POLYLINE_WIDTH * 0.5;
+    triangles.extend_from_slice(&[a + side, a - side, b - side]);
+    triangles.extend_from_slice(&[a + side, b - side, b + side]);
+  }
+  triangles
 }
\ No newline at end of file
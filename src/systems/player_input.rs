@@ -9,6 +9,9 @@ use ::resources::{
   Command,
   CommandChannel,
   Sounds,
+  GameSpeed,
+  Ability,
+  AbilityBudget,
   also_kills,
 };
 
@@ -22,10 +25,12 @@ impl<'s> System<'s> for PlayerInput {
   type SystemData = (
     Read<'s, InputHandler<String, String>>,
     Write<'s, CommandChannel>,
-    WriteExpect<'s, Sounds>,
+    Option<Write<'s, Sounds>>,
+    Read<'s, GameSpeed>,
+    Read<'s, AbilityBudget>,
   );
 
-  fn run(&mut self, (input, mut commands, mut sounds): Self::SystemData) {
+  fn run(&mut self, (input, mut commands, mut sounds, game_speed, ability_budget): Self::SystemData) {
     for action in input.bindings.actions() {
       let was_down = self.down_actions.contains(&action);
       let is_down = input.action_is_down(&action).unwrap_or(false);
@@ -45,13 +50,23 @@ impl<'s> System<'s> for PlayerInput {
           "prev_level" => Some(Command::PreviousLevel),
           "restart_level" => Some(Command::RestartLevel),
           "ram" => Some(Command::DropRam),
+          "drop_blocker" => Some(Command::DropBlocker),
+          "build_bridge" => Some(Command::BuildBridge),
+          "dig" => Some(Command::Dig),
+          "drop_floater" => Some(Command::DropFloater),
+          "drop_bomb" => Some(Command::DropBomb),
           "exodus" => Some(Command::Exodus),
+          "speed_up" => Some(Command::SpeedUp),
+          "slow_down" => Some(Command::SlowDown),
           o => {
             debug!("Unhandled input action: {:?}", o);
             None
           },
         };
-        if let Some(cmd) = cmd {
+        //Don't sacrifice the matriarch for an ability that's run out
+        let cmd = cmd.filter(|cmd| Ability::for_command(cmd).map_or(true, |a| ability_budget.available(a)));
+        //The pause overlay has its own buttons, nothing else should happen while paused
+        if let (Some(cmd), false) = (cmd, game_speed.paused) {
           let kill = also_kills(&cmd);
           commands.single_write(cmd);
           if kill {
@@ -68,8 +83,10 @@ impl<'s> System<'s> for PlayerInput {
         match axis.as_ref() {
           "move_z" => commands.single_write(Command::Zoom(value as f32)),
           "volume" => {
-            let v = sounds.volume + 0.01 * value as f32;
-            sounds.volume = v.min(1.0).max(0.0);
+            if let Some(sounds) = &mut sounds {
+              let v = sounds.volume + 0.01 * value as f32;
+              sounds.volume = v.min(1.0).max(0.0);
+            }
           },
           o => debug!("Unhandled input axis {} value: {}", o, value),
         }
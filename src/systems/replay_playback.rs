@@ -0,0 +1,56 @@
+use std::collections::VecDeque;
+
+use amethyst::ecs::prelude::*;
+
+use ::resources::{
+  CommandChannel,
+  PhysicsWorld,
+  Level,
+  LoadState,
+  Replay,
+  ReplayEvent,
+};
+
+///Sends the commands from a replay once the physics world reaches the step they were recorded on. Used in place of PlayerInput.
+///This runs once per frame after the physics steps so a command can land a few steps late when a frame takes more than one.
+pub struct ReplayPlayback {
+  events: VecDeque<ReplayEvent>,
+  //Step the first level was loaded on, the recorded steps are relative to it
+  start_step: Option<u64>,
+}
+
+impl ReplayPlayback {
+  pub fn new(replay: Replay) -> Self {
+    Self {
+      events: replay.events.into_iter().collect(),
+      start_step: None,
+    }
+  }
+}
+
+impl<'s> System<'s> for ReplayPlayback {
+  type SystemData = (
+    Read<'s, PhysicsWorld>,
+    Read<'s, Level>,
+    Write<'s, CommandChannel>,
+  );
+
+  fn run(&mut self, (physics_world, level, mut commands): Self::SystemData) {
+    if self.start_step.is_none() && level.load_state == LoadState::Loaded {
+      self.start_step = Some(level.load_step);
+    }
+
+    let start_step = match self.start_step {
+      Some(start_step) => start_step,
+      None => return,
+    };
+
+    let step = physics_world.get_step_count() - start_step;
+    //Events are recorded in order so only the front needs checking
+    while self.events.front().map_or(false, |e| e.step <= step) {
+      let event = self.events.pop_front().unwrap();
+      debug!("Replaying {:?} on step {}", event.command, step);
+      commands.single_write(event.command);
+    }
+  }
+}
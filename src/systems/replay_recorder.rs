@@ -0,0 +1,70 @@
+use amethyst::{
+  ecs::prelude::*,
+  shrev::ReaderId,
+};
+
+use ::resources::{
+  Command,
+  CommandChannel,
+  PhysicsWorld,
+  GameRng,
+  Level,
+  LoadState,
+  Replay,
+  ReplayEvent,
+};
+
+///Records every command along with the physics step it was sent on into the Replay resource.
+#[derive(Default)]
+pub struct ReplayRecorder {
+  command_reader: Option<ReaderId<Command>>,
+  //Step the first level was loaded on, steps are recorded relative to it
+  start_step: Option<u64>,
+}
+
+impl<'s> System<'s> for ReplayRecorder {
+  type SystemData = (
+    Read<'s, CommandChannel>,
+    Read<'s, PhysicsWorld>,
+    Read<'s, Level>,
+    Write<'s, Replay>,
+  );
+
+  fn setup(&mut self, res: &mut Resources) {
+    Self::SystemData::setup(res);
+    self.command_reader = Some(res.fetch_mut::<CommandChannel>().register_reader());
+
+    let seed = res.fetch::<GameRng>().seed;
+    res.fetch_mut::<Replay>().seed = seed;
+  }
+
+  fn run(&mut self, (commands, physics_world, level, mut replay): Self::SystemData) {
+    //The replay starts on whichever level gets picked in the menu
+    if self.start_step.is_none() && level.load_state == LoadState::Loaded {
+      self.start_step = Some(level.load_step);
+      replay.start_level = level.current_level;
+    }
+
+    //Read before returning so the reader doesn't fall behind
+    let events = commands.read(self.command_reader.as_mut().unwrap());
+    let start_step = match self.start_step {
+      Some(start_step) => start_step,
+      //Anything sent before then is menu navigation
+      None => return,
+    };
+
+    let step = physics_world.get_step_count() - start_step;
+    for command in events {
+      match command {
+        //Zoom only moves the camera and gets sent every frame the key is held
+        Command::Zoom(_) => {},
+        //Sent by the level watcher, not by the player
+        Command::FileChanged => {},
+        _ => replay.events.push(ReplayEvent {
+          step,
+          command: command.clone(),
+        }),
+      }
+    }
+  }
+}
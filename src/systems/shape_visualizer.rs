@@ -1,9 +1,15 @@
 
+use std::mem::swap;
+
 use amethyst::{
   assets::{
     AssetStorage,
     Loader,
   },
+  core::cgmath::{
+    Vector2,
+    Vector3,
+  },
   ecs::prelude::*,
   renderer::{
     Material,
@@ -16,13 +22,18 @@ use amethyst::{
   },
 };
 
+use rand::Rng;
 use random_color::{
   RandomColor,
   Luminosity,
 };
-use ::components::{
-  Shape as ShapeComponent,
-  Color,
+use ::{
+  components::{
+    Shape as ShapeComponent,
+    ShapeMesh,
+    Color,
+  },
+  resources::GameRng,
 };
 
 ///Finds shapes without meshes and creates meshes for them
@@ -41,9 +52,10 @@ impl<'s> System<'s> for ShapeVisualizer {
     ReadExpect<'s, AssetStorage<Mesh>>,
     ReadStorage<'s, MeshHandle>,
     ReadStorage<'s, SpriteRender>,
+    Write<'s, GameRng>,
   );
 
-  fn run(&mut self, (entities, shapes, colors, updater, material_defaults, loader, texture_storage, mesh_storage, meshes, sprites): Self::SystemData) {
+  fn run(&mut self, (entities, shapes, colors, updater, material_defaults, loader, texture_storage, mesh_storage, meshes, sprites, mut rng): Self::SystemData) {
     //Create meshes for shapes that don't have either a mesh or a sprite already
     for (entity, shape, _, _) in (&entities, &shapes, !&meshes, !&sprites).join() {
       //Material
@@ -51,8 +63,10 @@ impl<'s> System<'s> for ShapeVisualizer {
         if let Some(color) = colors.get(entity) {
           (*color).into()
         } else {
+          //RandomColor makes its own rng if it isn't given a seed
           let color = RandomColor::new()
             .luminosity(Luminosity::Light)
+            .seed(rng.gen::<i64>())
             .to_rgb_array();
           let color = Color::new(
             color[0] as f32 / 255.0,
@@ -74,7 +88,10 @@ impl<'s> System<'s> for ShapeVisualizer {
 
       //Mesh
       let mesh = {
-        let verts = shape.shape.generate_vertices::<Vec<PosNormTex>>(Some(shape.scale));
+        let verts = match shape.shape {
+          ShapeMesh::Generated(ref generated) => generated.generate_vertices::<Vec<PosNormTex>>(Some(shape.scale)),
+          ShapeMesh::Triangles(ref points) => triangle_vertices(points),
+        };
         let mesh = loader.load_from_data(verts.into(), (), &mesh_storage);
         mesh
       };
@@ -95,4 +112,27 @@ fn create_colour_material(
     albedo,
     ..material_defaults.0.clone()
   }
+}
+
+///Flat triangles facing the camera. Winding is fixed up so none get culled
+fn triangle_vertices(points: &[Vector2<f32>]) -> Vec<PosNormTex> {
+  let mut verts = Vec::with_capacity(points.len());
+  for triangle in points.chunks(3) {
+    if triangle.len() < 3 {
+      break;
+    }
+    let (a, mut b, mut c) = (triangle[0], triangle[1], triangle[2]);
+    //Clockwise triangles get flipped to anticlockwise
+    if (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x) < 0.0 {
+      swap(&mut b, &mut c);
+    }
+    for p in &[a, b, c] {
+      verts.push(PosNormTex {
+        position: Vector3::new(p.x, p.y, 0.0),
+        normal: Vector3::new(0.0, 0.0, 1.0),
+        tex_coord: Vector2::new(0.0, 0.0),
+      });
+    }
+  }
+  verts
 }
\ No newline at end of file
@@ -1,7 +1,6 @@
 use amethyst::{
   core::{
     transform::components::Transform,
-    timing::Time,
     cgmath::Vector2,
   },
   ecs::prelude::*,
@@ -54,19 +53,18 @@ impl<'s> System<'s> for Spawner {
   type SystemData = (
     Entities<'s>,
     ReadStorage<'s, Transform>,
-    Read<'s, Time>,
     WriteStorage<'s, SpawnerComponent>,
     Write<'s, SpawnStats>,
     Write<'s, PhysicsWorld>,
-    ReadExpect<'s, Sounds>,
+    Option<Read<'s, Sounds>>,
     Read<'s, AssetStorage<Source>>,
     Option<Read<'s, Output>>,
     Read<'s, LazyUpdate>,
     Read<'s, SpawnerConfig>,
   );
 
-  fn run(&mut self, (entities, transforms, time, mut spawners, mut spawn_stats, mut physics_world, sounds, source_storage, output, updater, spawner_config): Self::SystemData) {
-    let delta = time.delta_seconds();
+  fn run(&mut self, (entities, transforms, mut spawners, mut spawn_stats, mut physics_world, sounds, source_storage, output, updater, spawner_config): Self::SystemData) {
+    let delta = physics_world.get_frame_delta();
 
     //Increase elapsed time for all Spawners
     for (e, s, t) in (&entities, &mut spawners, &transforms).join() {
@@ -106,7 +104,7 @@ impl<'s> System<'s> for Spawner {
 
         debug!("Spawner ({:?}) spawned: {:?}", e, new);
 
-        if let Some(output) = &output {
+        if let (Some(sounds), Some(output)) = (&sounds, &output) {
           sounds.play_spawn(&source_storage, output);
         }
       }
@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use amethyst::{
+  ecs::prelude::*,
+  renderer::Hidden,
+};
+
+use ::{
+  components::{
+    Walker,
+    Collider,
+    Switch,
+    Door,
+  },
+  resources::PhysicsWorld,
+};
+
+///Checks for walkers on switches and opens or closes the doors they target
+#[derive(Default)]
+pub struct Switches;
+
+impl<'s> System<'s> for Switches {
+  type SystemData = (
+    Entities<'s>,
+    ReadStorage<'s, Walker>,
+    WriteStorage<'s, Switch>,
+    WriteStorage<'s, Door>,
+    ReadStorage<'s, Collider>,
+    WriteStorage<'s, Hidden>,
+    Write<'s, PhysicsWorld>,
+  );
+
+  fn run(&mut self, (entities, walkers, mut switches, mut doors, colliders, mut hidden, mut physics_world): Self::SystemData) {
+    //Ids of every door with a pressed switch
+    let mut toggled = HashSet::new();
+    for (switch, sensor) in (&mut switches, &colliders).join() {
+      let walker_on = match physics_world.get_proximity(&sensor.collider_handle) {
+        Some(proxs) => proxs
+          .iter()
+          .filter_map(|prox| physics_world.get_entity_for_collider(prox))
+          .any(|entity| walkers.contains(entity)),
+        None => false,
+      };
+
+      switch.pressed = walker_on || (switch.latch && switch.pressed);
+      if switch.pressed {
+        toggled.extend(switch.targets.iter().cloned());
+      }
+    }
+
+    for (e, door, collider) in (&entities, &mut doors, &colliders).join() {
+      let open = door.open_by_default != toggled.contains(&door.id);
+      if open == door.open {
+        continue;
+      }
+
+      debug!("Door {} ({:?}) {}", door.id, e, if open { "opened" } else { "closed" });
+      door.open = open;
+      physics_world.set_collider_enabled(collider.collider_handle, !open);
+      if open {
+        hidden
+          .insert(e, Hidden)
+          .expect("Failed to insert component");
+      } else {
+        hidden.remove(e);
+      }
+    }
+  }
+}
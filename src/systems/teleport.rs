@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use amethyst::ecs::prelude::*;
+
+use nphysics2d::math::Velocity;
+
+use ::{
+  config::PhysicsConfig,
+  components::{
+    Walker,
+    Collider,
+    Teleporter,
+  },
+  resources::{
+    PhysicsWorld,
+  },
+};
+
+///Moves walkers that go into a teleporter to the other end of the pair. Walkers can't be teleported again until
+///their cooldown runs out, otherwise they'd bounce straight back from the other end.
+#[derive(Default)]
+pub struct Teleport {
+  //Seconds left before each walker can be teleported again
+  cooldowns: HashMap<Entity, f32>,
+}
+
+impl<'s> System<'s> for Teleport {
+  type SystemData = (
+    Entities<'s>,
+    ReadStorage<'s, Walker>,
+    ReadStorage<'s, Teleporter>,
+    WriteStorage<'s, Collider>,
+    Write<'s, PhysicsWorld>,
+    Read<'s, PhysicsConfig>,
+  );
+
+  fn run(&mut self, (entities, walkers, teleporters, mut colliders, mut physics_world, physics_config): Self::SystemData) {
+    let delta = physics_world.get_frame_delta();
+    //Forget about walkers that have gone or cooled down
+    self.cooldowns.retain(|e, seconds| {
+      *seconds -= delta;
+      *seconds > 0.0 && entities.is_alive(*e)
+    });
+
+    let mut to_teleport = Vec::new();
+    for (teleporter, sensor) in (&teleporters, &colliders).join() {
+      if let Some(proxs) = physics_world.get_proximity(&sensor.collider_handle) {
+        for prox in proxs {
+          if let Some(entity) = physics_world.get_entity_for_collider(prox) {
+            //Only works on walkers
+            if !walkers.contains(entity) || self.cooldowns.contains_key(&entity) {
+              continue;
+            }
+            self.cooldowns.insert(entity, physics_config.teleporter_cooldown);
+            to_teleport.push((entity, teleporter.destination, teleporter.keep_velocity));
+          }
+        }
+      }
+    }
+
+    for (entity, destination, keep_velocity) in to_teleport {
+      if let Some(c) = colliders.get_mut(entity) {
+        debug!("Teleporting {:?} to {:?}", entity, destination);
+        physics_world.set_body_position(c.body_handle, &destination);
+        if !keep_velocity {
+          if let Some(body) = physics_world.world.rigid_body_mut(c.body_handle) {
+            body.set_velocity(Velocity::zero());
+          }
+        }
+
+        //Both ends of the lerp are moved otherwise it'd draw the walker sliding across the level between steps
+        c.transform_current.translation.x = destination.x;
+        c.transform_current.translation.y = destination.y;
+        c.transform_next.translation.x = destination.x;
+        c.transform_next.translation.y = destination.y;
+      }
+    }
+  }
+}